@@ -0,0 +1,663 @@
+//! Analog-digital conversion traits
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use nb;
+
+pub mod shared;
+
+use crate::time::Duration;
+
+/// A trait to identify MCU pins that can be used as inputs to an ADC channel.
+///
+/// This trait denotes an object, i.e. a GPIO pin, that is ready for use as an input to the ADC. As
+/// ADCs channels can be supplied by multiple pins, this trait defines the relationship between the
+/// physical interface and the ADC sampling buffer.
+///
+/// # Contract
+///
+/// - HALs MUST only implement `Channel<ADC>` for pins that are connected to `ADC`, and only in the
+///   pin mode(s) that route the pin to the ADC. Generic drivers taking `PIN: Channel<ADC>` can then
+///   rely on the type system to only ever sample the pin they were given.
+/// - `channel` MUST always return the same ID for a given pin.
+///
+/// Channels that are only known at runtime can be wrapped in an [`AnyChannel`]; the ADC then
+/// checks the ID when sampling it.
+///
+/// [`AnyChannel`]: struct.AnyChannel.html
+///
+/// ```
+/// # use core::marker::PhantomData;
+/// # use embedded_hal::adc::Channel;
+///
+/// struct Adc1; // Example ADC with single bank of 8 channels
+/// struct Gpio1Pin1<MODE>(PhantomData<MODE>);
+/// struct Analog(()); // marker type to denote a pin in "analog" mode
+///
+/// // GPIO 1 pin 1 can supply an ADC channel when it is configured in Analog mode
+/// impl Channel<Adc1> for Gpio1Pin1<Analog> {
+///     type ID = u8; // ADC channels are identified numerically
+///
+///     fn channel(&self) -> u8 {
+///         7_u8 // GPIO pin 1 is connected to ADC channel 7
+///     }
+/// }
+///
+/// struct Adc2; // ADC with two banks of 16 channels
+/// struct Gpio2PinA<MODE>(PhantomData<MODE>);
+/// struct AltFun(()); // marker type to denote some alternate function mode for the pin
+///
+/// // GPIO 2 pin A can supply an ADC channel when it's configured in some alternate function mode
+/// impl Channel<Adc2> for Gpio2PinA<AltFun> {
+///     type ID = (u8, u8); // ADC channels are identified by bank number and channel number
+///
+///     fn channel(&self) -> (u8, u8) {
+///         (0, 3) // bank 0 channel 3
+///     }
+/// }
+/// ```
+pub trait Channel<ADC> {
+    /// Channel ID type
+    ///
+    /// A type used to identify this ADC channel. For example, if the ADC has eight channels, this
+    /// might be a `u8`. If the ADC has multiple banks of channels, it could be a tuple, like
+    /// `(u8: bank_id, u8: channel_id)`.
+    type ID;
+
+    /// Get the specific ID that identifies this channel, for example `0_u8` for the first ADC
+    /// channel, if Self::ID is u8.
+    fn channel(&self) -> Self::ID;
+}
+
+/// An ADC channel identified at runtime
+///
+/// This is the escape hatch for applications that pick channels at runtime, e.g. from a
+/// configuration file. Unlike pin types, an `AnyChannel` doesn't prove that the channel exists:
+/// ADC implementations check the ID when sampling the channel and return an error if it's invalid.
+///
+/// ```
+/// use embedded_hal::adc::{AnyChannel, Channel};
+///
+/// struct Adc1;
+///
+/// let channel: AnyChannel<Adc1, u8> = AnyChannel::new(4);
+/// // copied, even though `Adc1` isn't `Copy`
+/// let copy = channel;
+/// assert_eq!(channel.channel(), copy.channel());
+/// ```
+pub struct AnyChannel<ADC, ID> {
+    id: ID,
+    _adc: PhantomData<ADC>,
+}
+
+impl<ADC, ID> AnyChannel<ADC, ID> {
+    /// Wraps the channel ID `id`
+    pub fn new(id: ID) -> Self {
+        AnyChannel {
+            id,
+            _adc: PhantomData,
+        }
+    }
+
+    /// Erases the type of `pin`, keeping its channel ID
+    pub fn from_pin<PIN>(pin: &PIN) -> Self
+    where
+        PIN: Channel<ADC, ID = ID>,
+    {
+        AnyChannel::new(pin.channel())
+    }
+}
+
+// not derived, which would require `ADC` to implement the traits as well
+impl<ADC, ID> Clone for AnyChannel<ADC, ID>
+where
+    ID: Clone,
+{
+    fn clone(&self) -> Self {
+        AnyChannel::new(self.id.clone())
+    }
+}
+
+impl<ADC, ID> Copy for AnyChannel<ADC, ID> where ID: Copy {}
+
+impl<ADC, ID> fmt::Debug for AnyChannel<ADC, ID>
+where
+    ID: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnyChannel").field("id", &self.id).finish()
+    }
+}
+
+impl<ADC, ID> Channel<ADC> for AnyChannel<ADC, ID>
+where
+    ID: Clone,
+{
+    type ID = ID;
+
+    fn channel(&self) -> ID {
+        self.id.clone()
+    }
+}
+
+/// The internal reference voltage channel (VREFINT)
+///
+/// HALs implement `Channel<ADC>` for this type on the ADCs that can sample the internal reference,
+/// so drivers can request it without knowing its channel number.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, OneShot, VRefInt};
+///
+/// fn read_vrefint<A, ADC>(adc: &mut A) -> nb::Result<u16, A::Error>
+/// where
+///     A: OneShot<ADC, u16, VRefInt>,
+///     VRefInt: Channel<ADC>,
+/// {
+///     adc.try_read(&mut VRefInt)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VRefInt;
+
+/// The backup battery voltage channel (VBAT)
+///
+/// Usually sampled through an internal voltage divider, see the documentation of the HAL.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VBat;
+
+/// The internal temperature sensor channel
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Temperature;
+
+/// ADCs that sample on single channels per request, and do so at the time of the request.
+///
+/// This trait is the interface to an ADC that is configured to read a specific channel at the time
+/// of the request (in contrast to continuous asynchronous sampling).
+///
+/// ```
+/// use embedded_hal::adc::{Channel, OneShot};
+///
+/// struct MyAdc; // 10-bit ADC, with 5 channels
+/// # impl MyAdc {
+/// #     pub fn power_up(&mut self) {}
+/// #     pub fn power_down(&mut self) {}
+/// #     pub fn do_conversion(&mut self, chan: u8) -> u16 { 0xAA55_u16 }
+/// # }
+///
+/// impl<WORD, PIN> OneShot<MyAdc, WORD, PIN> for MyAdc
+/// where
+///    WORD: From<u16>,
+///    PIN: Channel<MyAdc, ID=u8>,
+/// {
+///    type Error = ();
+///
+///    fn try_read(&mut self, pin: &mut PIN) -> nb::Result<WORD, Self::Error> {
+///        let chan = 1 << pin.channel();
+///        self.power_up();
+///        let result = self.do_conversion(chan);
+///        self.power_down();
+///        Ok(result.into())
+///    }
+/// }
+/// ```
+pub trait OneShot<ADC, Word, Pin: Channel<ADC>> {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Request that the ADC begin a conversion on the specified pin
+    ///
+    /// This method takes a `Pin` reference, as it is expected that the ADC will be able to sample
+    /// whatever channel underlies the pin.
+    fn try_read(&mut self, pin: &mut Pin) -> nb::Result<Word, Self::Error>;
+}
+
+/// ADCs that convert a sequence of channels in a single scan
+///
+/// Hardware scan engines convert several channels back-to-back with a single trigger, which is
+/// faster and more tightly synchronized than a series of `OneShot` reads.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, Scan};
+///
+/// fn read_all<A, ADC, X, Y, Z>(adc: &mut A, x: &X, y: &Y, z: &Z) -> Result<[u16; 3], A::Error>
+/// where
+///     A: Scan<ADC, u16, ID = u8>,
+///     X: Channel<ADC, ID = u8>,
+///     Y: Channel<ADC, ID = u8>,
+///     Z: Channel<ADC, ID = u8>,
+/// {
+///     adc.try_set_sequence(&[x.channel(), y.channel(), z.channel()])?;
+///
+///     let mut samples = [0; 3];
+///     nb::block!(adc.try_scan(&mut samples))?;
+///     Ok(samples)
+/// }
+/// ```
+pub trait Scan<ADC, Word> {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Channel ID type, the same as the `ID` of the `Channel`s of this ADC
+    type ID;
+
+    /// Sets the channels converted by `try_scan`, in conversion order
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the sequence is longer than what the ADC supports.
+    fn try_set_sequence(&mut self, channels: &[Self::ID]) -> Result<(), Self::Error>;
+
+    /// Converts every channel of the sequence and writes the results to `results`, in sequence
+    /// order
+    ///
+    /// The first call starts the scan; `WouldBlock` is returned until all the conversions are
+    /// done.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the length of `results` doesn't match the length of the
+    /// sequence.
+    fn try_scan(&mut self, results: &mut [Word]) -> nb::Result<(), Self::Error>;
+}
+
+/// ADCs that convert a channel continuously
+///
+/// Once started, the ADC converts the channel over and over on its own and the application only
+/// picks up the newest sample, e.g. from a control loop.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, Continuous};
+///
+/// fn control_loop<A, ADC, PIN>(adc: &mut A, pin: &mut PIN) -> Result<(), A::Error>
+/// where
+///     A: Continuous<ADC, u16, PIN>,
+///     PIN: Channel<ADC>,
+/// {
+///     adc.try_start(pin)?;
+///
+///     loop {
+///         let sample = nb::block!(adc.try_read_latest())?;
+///         // update the controller output
+/// #       break;
+///     }
+///
+///     adc.try_stop()
+/// }
+/// ```
+pub trait Continuous<ADC, Word, Pin: Channel<ADC>> {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Starts converting the channel of `pin` continuously
+    fn try_start(&mut self, pin: &mut Pin) -> Result<(), Self::Error>;
+
+    /// Stops the conversions
+    fn try_stop(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the newest sample
+    ///
+    /// Samples converted since the last call, but not returned, are dropped. `WouldBlock` is
+    /// returned if no new sample was converted since the last call.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the conversions were not started.
+    fn try_read_latest(&mut self) -> nb::Result<Word, Self::Error>;
+}
+
+/// ADCs whose resolution can be queried
+///
+/// Implemented by fixed resolution ADCs too; [`SetResolution`] adds the configuration.
+///
+/// [`SetResolution`]: trait.SetResolution.html
+///
+/// ```
+/// use embedded_hal::adc::Resolution;
+///
+/// /// Converts a raw code to millivolts, for a 3.3 V reference
+/// fn to_millivolts<A: Resolution>(adc: &A, code: u32) -> Result<u32, A::Error> {
+///     Ok(code * 3300 / adc.try_max_code()?)
+/// }
+/// ```
+pub trait Resolution {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Returns the resolution of the conversions, in bits
+    fn try_get_resolution(&self) -> Result<u8, Self::Error>;
+
+    /// Returns the largest code a conversion can return at the current resolution, e.g. `4095`
+    /// for a 12-bit ADC
+    ///
+    /// Saturates at `0xFFFF_FFFF` for resolutions of 32 bits or more.
+    fn try_max_code(&self) -> Result<u32, Self::Error> {
+        let bits = self.try_get_resolution()?;
+        if bits >= 32 {
+            Ok(0xFFFF_FFFF)
+        } else {
+            Ok((1 << bits) - 1)
+        }
+    }
+}
+
+/// ADCs whose resolution can be configured
+///
+/// ```
+/// use embedded_hal::adc::SetResolution;
+///
+/// /// Trades resolution for conversion speed
+/// fn fast_mode<A: SetResolution>(adc: &mut A) -> Result<(), A::Error> {
+///     if adc.try_get_resolution()? > 8 {
+///         adc.try_set_resolution(8)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait SetResolution: Resolution {
+    /// Sets the resolution of the conversions, in bits
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the ADC doesn't support `bits` of resolution.
+    fn try_set_resolution(&mut self, bits: u8) -> Result<(), Self::Error>;
+}
+
+/// DMA-backed ADC sampling
+///
+/// Fills a buffer with samples without CPU intervention, for high-rate data acquisition.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, ReadDma};
+/// use embedded_hal::dma::Transfer;
+///
+/// static mut SAMPLES: [u16; 256] = [0; 256];
+///
+/// fn acquire<A, ADC, PIN>(adc: A, pin: &PIN) -> A
+/// where
+///     A: ReadDma<ADC, u16, ID = u8>,
+///     PIN: Channel<ADC, ID = u8>,
+/// {
+///     let buffer = unsafe { &mut SAMPLES };
+///     let transfer = adc.try_read_dma(&[pin.channel()], buffer).ok().unwrap();
+///
+///     // .. do other work while the samples are being acquired
+///
+///     let (_samples, adc) = transfer.try_wait().ok().unwrap();
+///     adc
+/// }
+/// ```
+pub trait ReadDma<ADC, Word: 'static>: Sized {
+    /// An enumeration of errors that can occur while starting the transfer
+    type Error;
+
+    /// Channel ID type, the same as the `ID` of the `Channel`s of this ADC
+    type ID;
+
+    /// Handle to the ongoing transfer
+    ///
+    /// Errors that occur while the transfer is in progress (e.g. an overrun) are reported by this
+    /// handle.
+    type Transfer: crate::dma::Transfer<Output = (&'static mut [Word], Self)>;
+
+    /// Starts filling `buffer` with samples of `channels`
+    ///
+    /// The channels are converted in order, over and over, until the buffer is full: with more
+    /// than one channel the samples are interleaved in the buffer.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the ADC can't convert this sequence of channels.
+    fn try_read_dma(
+        self,
+        channels: &[Self::ID],
+        buffer: &'static mut [Word],
+    ) -> Result<Self::Transfer, Self::Error>;
+}
+
+/// ADCs that know their reference voltage
+///
+/// Provides the conversion from raw codes to millivolts that every ADC user otherwise writes by
+/// hand.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, ConversionError, OneShot, ReferenceVoltage};
+///
+/// fn battery_mv<A, ADC, PIN, E>(adc: &mut A, pin: &mut PIN) -> Result<u32, ConversionError<E>>
+/// where
+///     A: OneShot<ADC, u32, PIN, Error = E> + ReferenceVoltage<Error = E>,
+///     PIN: Channel<ADC>,
+/// {
+///     let code = nb::block!(adc.try_read(pin)).map_err(ConversionError::Adc)?;
+///     // the battery is connected through a 1:2 voltage divider
+///     Ok(adc.try_to_millivolts(code)? * 2)
+/// }
+/// ```
+pub trait ReferenceVoltage: Resolution {
+    /// Returns the effective reference voltage, in millivolts
+    ///
+    /// Where supported, implementations measure the internal reference (VREFINT) against its
+    /// factory calibration to compute the actual supply voltage rather than returning a nominal
+    /// value.
+    fn try_reference_millivolts(&mut self) -> Result<u32, Self::Error>;
+
+    /// Converts a raw conversion result to millivolts
+    ///
+    /// # Errors
+    ///
+    /// `ConversionError::ZeroResolution` is returned if the resolution is 0 bits.
+    fn try_to_millivolts(&mut self, code: u32) -> Result<u32, ConversionError<Self::Error>> {
+        let reference = self
+            .try_reference_millivolts()
+            .map_err(ConversionError::Adc)?;
+        let max_code = self.try_max_code().map_err(ConversionError::Adc)?;
+
+        if max_code == 0 {
+            return Err(ConversionError::ZeroResolution);
+        }
+
+        Ok((u64::from(code) * u64::from(reference) / u64::from(max_code)) as u32)
+    }
+}
+
+/// Error returned by [`ReferenceVoltage::try_to_millivolts`]
+///
+/// [`ReferenceVoltage::try_to_millivolts`]: trait.ReferenceVoltage.html#method.try_to_millivolts
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConversionError<E> {
+    /// Error reported by the ADC
+    Adc(E),
+    /// The ADC reports a resolution of 0 bits, so codes can't be scaled
+    ZeroResolution,
+}
+
+/// ADCs with an analog watchdog
+///
+/// The watchdog compares every conversion of a channel against a window and flags the samples
+/// that fall out of it, e.g. to detect a low battery or an overcurrent without checking every
+/// sample in software.
+///
+/// ```
+/// use embedded_hal::adc::{AnalogWatchdog, Channel};
+///
+/// fn guard_current<A, ADC, SHUNT>(adc: &mut A, shunt: &SHUNT) -> Result<(), A::Error>
+/// where
+///     A: AnalogWatchdog<ADC, u16, ID = u8>,
+///     SHUNT: Channel<ADC, ID = u8>,
+/// {
+///     adc.try_set_window(shunt.channel(), 0, 3_000)?;
+///
+///     // .. start the conversions
+///
+///     nb::block!(adc.try_wait_out_of_window())?;
+///     // overcurrent: shut the power stage down
+///     Ok(())
+/// }
+/// ```
+pub trait AnalogWatchdog<ADC, Word> {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Channel ID type, the same as the `ID` of the `Channel`s of this ADC
+    type ID;
+
+    /// Starts watching `channel`, flagging conversions lower than `low` or higher than `high`
+    fn try_set_window(
+        &mut self,
+        channel: Self::ID,
+        low: Word,
+        high: Word,
+    ) -> Result<(), Self::Error>;
+
+    /// Stops watching
+    fn try_disable_window(&mut self) -> Result<(), Self::Error>;
+
+    /// Waits for a conversion out of the window
+    ///
+    /// Returns `WouldBlock` until a conversion falls out of the window, then clears the event.
+    fn try_wait_out_of_window(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// ADCs whose sampling time can be configured per channel
+///
+/// Sources with a high output impedance need a longer sampling time to charge the sampling
+/// capacitor of the ADC; drivers for such sources request it through this trait.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, SetSampleTime};
+/// use embedded_hal::time::U32Ext;
+///
+/// fn configure<A, ADC, NTC>(adc: &mut A, ntc: &NTC) -> Result<(), A::Error>
+/// where
+///     A: SetSampleTime<ID = u8>,
+///     NTC: Channel<ADC, ID = u8>,
+/// {
+///     // the thermistor divider has a 100 kOhm output impedance
+///     adc.try_set_sample_time(ntc.channel(), 10.micros())
+/// }
+/// ```
+pub trait SetSampleTime {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Channel ID type, the same as the `ID` of the `Channel`s of this ADC
+    type ID;
+
+    /// Sets the sampling time of `channel` to at least `time`
+    ///
+    /// Implementations round `time` up to the closest sampling time supported by the hardware.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `time` is longer than the longest supported sampling time.
+    fn try_set_sample_time<T>(&mut self, channel: Self::ID, time: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>;
+}
+
+/// Edge of the trigger signal that starts a conversion
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerEdge {
+    /// Rising edge
+    Rising,
+    /// Falling edge
+    Falling,
+    /// Both edges
+    Both,
+}
+
+/// ADCs whose conversions are started by a hardware trigger
+///
+/// Covers regular conversions started by an external event as well as injected conversions.
+/// Triggering from a timer samples at a precise point in time, e.g. in the middle of a PWM period
+/// to measure a motor phase current.
+///
+/// ```
+/// use embedded_hal::adc::{Channel, TriggerEdge, Triggered};
+///
+/// fn sample_phases<A, ADC, U, V>(
+///     adc: &mut A,
+///     u: &U,
+///     v: &V,
+///     center: A::Trigger,
+/// ) -> Result<(), A::Error>
+/// where
+///     A: Triggered<ADC, u16, ID = u8>,
+///     U: Channel<ADC, ID = u8>,
+///     V: Channel<ADC, ID = u8>,
+/// {
+///     adc.try_start(&[u.channel(), v.channel()], center, TriggerEdge::Rising)?;
+///
+///     loop {
+///         let mut currents = [0; 2];
+///         nb::block!(adc.try_read(&mut currents))?;
+///         // run the current controller
+/// #       break;
+///     }
+///
+///     adc.try_stop()
+/// }
+/// ```
+pub trait Triggered<ADC, Word> {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Channel ID type, the same as the `ID` of the `Channel`s of this ADC
+    type ID;
+
+    /// Identifies the events that can trigger a conversion, e.g. timer events or external pins
+    type Trigger;
+
+    /// Converts `channels` in order every time `trigger` fires on `edge`
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the ADC can't convert this sequence of channels, or if
+    /// `trigger` can't be connected to this ADC.
+    fn try_start(
+        &mut self,
+        channels: &[Self::ID],
+        trigger: Self::Trigger,
+        edge: TriggerEdge,
+    ) -> Result<(), Self::Error>;
+
+    /// Stops reacting to the trigger
+    fn try_stop(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the results of the conversions started by the last trigger, in sequence order
+    ///
+    /// `WouldBlock` is returned until new results are available since the previous call.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the length of `results` doesn't match the number of channels.
+    fn try_read(&mut self, results: &mut [Word]) -> nb::Result<(), Self::Error>;
+}
+
+/// ADCs with hardware oversampling
+///
+/// The ADC accumulates `ratio` conversions and right-shifts the sum by `shift` bits before
+/// returning it as a single result, trading conversion speed for lower noise and more resolution.
+///
+/// ```
+/// use embedded_hal::adc::SetOversampling;
+///
+/// /// Accumulates 16 samples and keeps 2 extra bits of resolution
+/// fn low_noise<A: SetOversampling>(adc: &mut A) -> Result<(), A::Error> {
+///     adc.try_set_oversampling(16, 2)
+/// }
+/// ```
+pub trait SetOversampling {
+    /// Error type returned by ADC methods
+    type Error;
+
+    /// Enables oversampling with the given `ratio` and `shift`
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the hardware doesn't support this ratio or shift.
+    fn try_set_oversampling(&mut self, ratio: u16, shift: u8) -> Result<(), Self::Error>;
+
+    /// Disables oversampling
+    fn try_disable_oversampling(&mut self) -> Result<(), Self::Error>;
+}
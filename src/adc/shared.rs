@@ -0,0 +1,103 @@
+//! Shared ADC
+//!
+//! `OneShot` takes the ADC by `&mut self`, so a single driver ends up owning it. A [`SharedAdc`]
+//! handle lets several drivers each sample their own channels of the same ADC, shared through a
+//! [`Mutex`]. Use a critical section `Mutex`, aliased as [`CriticalSectionAdc`], when the drivers
+//! run in different execution contexts, e.g. the main loop and interrupt handlers.
+//!
+//! Each conversion runs to completion while the ADC is locked, so a handle never gets the result
+//! of a conversion started by another handle.
+//!
+//! [`SharedAdc`]: struct.SharedAdc.html
+//! [`Mutex`]: ../../mutex/trait.Mutex.html
+//! [`CriticalSectionAdc`]: type.CriticalSectionAdc.html
+//!
+//! # Examples
+//!
+//! ```
+//! use core::cell::RefCell;
+//! use embedded_hal::adc::shared::SharedAdc;
+//! use embedded_hal::adc::{Channel, OneShot};
+//!
+//! struct Battery<A, P> {
+//!     adc: A,
+//!     pin: P,
+//! }
+//!
+//! impl<A: OneShot<Adc1, u16, P>, P: Channel<Adc1>> Battery<A, P> {
+//!     fn millivolts(&mut self) -> Result<u32, A::Error> {
+//!         let sample = nb::block!(self.adc.try_read(&mut self.pin))?;
+//!         Ok(u32::from(sample) * 6600 / 4096)
+//!     }
+//! }
+//!
+//! let adc = RefCell::new(Adc1);
+//!
+//! let mut battery = Battery { adc: SharedAdc::new(&adc), pin: Pa0 };
+//! let mut light = SharedAdc::new(&adc);
+//!
+//! battery.millivolts().unwrap();
+//! nb::block!(light.try_read(&mut Pa1)).unwrap();
+//!
+//! # use core::convert::Infallible;
+//! # struct Adc1;
+//! # struct Pa0;
+//! # struct Pa1;
+//! # impl Channel<Adc1> for Pa0 { type ID = u8; fn channel(&self) -> u8 { 0 } }
+//! # impl Channel<Adc1> for Pa1 { type ID = u8; fn channel(&self) -> u8 { 1 } }
+//! # impl<P: Channel<Adc1>> OneShot<Adc1, u16, P> for Adc1 {
+//! #     type Error = Infallible;
+//! #     fn try_read(&mut self, _: &mut P) -> nb::Result<u16, Infallible> { Ok(2048) }
+//! # }
+//! ```
+
+#[cfg(feature = "critical-section")]
+use core::cell::RefCell;
+
+use super::{Channel, OneShot};
+use crate::mutex::Mutex;
+
+/// Handle to an ADC shared through a `Mutex`
+///
+/// # Panics
+///
+/// With a `RefCell`, conversions panic if the ADC is already borrowed, e.g. when a handle is used
+/// from an interrupt handler while the main loop is sampling through another handle.
+pub struct SharedAdc<'a, M> {
+    adc: &'a M,
+}
+
+impl<'a, M> SharedAdc<'a, M>
+where
+    M: Mutex,
+{
+    /// Creates a new handle to the shared `adc`
+    pub fn new(adc: &'a M) -> Self {
+        SharedAdc { adc }
+    }
+}
+
+/// Handle to an ADC shared through a critical section `Mutex`
+///
+/// Every conversion is executed inside a critical section, so handles can be used from different
+/// execution contexts.
+#[cfg(feature = "critical-section")]
+pub type CriticalSectionAdc<'a, T> = SharedAdc<'a, critical_section::Mutex<RefCell<T>>>;
+
+impl<'a, M, ADC, Word, Pin> OneShot<ADC, Word, Pin> for SharedAdc<'a, M>
+where
+    M: Mutex,
+    M::Data: OneShot<ADC, Word, Pin>,
+    Pin: Channel<ADC>,
+{
+    type Error = <M::Data as OneShot<ADC, Word, Pin>>::Error;
+
+    /// Converts `pin`, blocking with the ADC locked until the conversion is done
+    ///
+    /// Never returns `WouldBlock`.
+    fn try_read(&mut self, pin: &mut Pin) -> nb::Result<Word, Self::Error> {
+        self.adc
+            .lock(|adc| block!(adc.try_read(pin)))
+            .map_err(nb::Error::Other)
+    }
+}
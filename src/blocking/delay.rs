@@ -6,9 +6,26 @@
 //! higher level abstractions like I/O operations with timeouts. OTOH, these delays traits only
 //! provide *blocking* functionality. Note that you can also use the `timer::CountDown` trait to
 //! implement blocking delays.
+//!
+//! # Which trait should a driver use?
+//!
+//! Drivers should bound on `DelayNs`: a single trait covers nanosecond, microsecond and
+//! millisecond delays. The `DelayMs` and `DelayUs` traits force drivers to pick (and bound on) a
+//! specific integer width and are kept for existing drivers.
+//!
+//! HALs that already implement `timer::CountDown` can provide all the delay traits by wrapping
+//! the timer in a [`Delay`].
+//!
+//! [`Delay`]: struct.Delay.html
+
+use crate::time::Duration;
+#[cfg(feature = "nb")]
+use crate::timer::CountDown;
 
 /// Millisecond delay
 ///
+/// New drivers should prefer `DelayNs`.
+///
 /// `UXX` denotes the range type of the delay time. `UXX` can be `u8`, `u16`, etc. A single type can
 /// implement this trait for different types of `UXX`.
 pub trait DelayMs<UXX> {
@@ -21,6 +38,8 @@ pub trait DelayMs<UXX> {
 
 /// Microsecond delay
 ///
+/// New drivers should prefer `DelayNs`.
+///
 /// `UXX` denotes the range type of the delay time. `UXX` can be `u8`, `u16`, etc. A single type can
 /// implement this trait for different types of `UXX`.
 pub trait DelayUs<UXX> {
@@ -30,3 +49,212 @@ pub trait DelayUs<UXX> {
     /// Pauses execution for `us` microseconds
     fn try_delay_us(&mut self, us: UXX) -> Result<(), Self::Error>;
 }
+
+/// Delay with up to nanosecond precision
+///
+/// HALs only need to implement `try_delay_ns`; microsecond and millisecond delays are provided.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::delay::DelayNs;
+/// use embedded_hal::digital::OutputPin;
+/// use embedded_hal::time::U32Ext;
+///
+/// fn reset<P, D, E>(pin: &mut P, delay: &mut D) -> Result<(), E>
+/// where
+///     P: OutputPin<Error = E>,
+///     D: DelayNs<Error = E>,
+/// {
+///     pin.try_set_low()?;
+///     delay.try_delay_micros(10)?;
+///     pin.try_set_high()?;
+///     // wait for the device to boot
+///     delay.try_delay(5.millis())
+/// }
+/// ```
+pub trait DelayNs {
+    /// Enumeration of `DelayNs` errors
+    type Error;
+
+    /// Pauses execution for at minimum `ns` nanoseconds
+    ///
+    /// Pause can be longer if the implementation requires it due to precision/timing issues.
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), Self::Error>;
+
+    /// Pauses execution for at minimum `us` microseconds
+    ///
+    /// Pause can be longer if the implementation requires it due to precision/timing issues.
+    fn try_delay_micros(&mut self, mut us: u32) -> Result<(), Self::Error> {
+        // the largest number of microseconds that fits in `u32` nanoseconds
+        const MAX_US: u32 = 4_294_967;
+
+        while us > MAX_US {
+            us -= MAX_US;
+            self.try_delay_ns(MAX_US * 1_000)?;
+        }
+
+        self.try_delay_ns(us * 1_000)
+    }
+
+    /// Pauses execution for at minimum `ms` milliseconds
+    ///
+    /// Pause can be longer if the implementation requires it due to precision/timing issues.
+    fn try_delay_millis(&mut self, mut ms: u32) -> Result<(), Self::Error> {
+        // the largest number of milliseconds that fits in `u32` nanoseconds
+        const MAX_MS: u32 = 4_294;
+
+        while ms > MAX_MS {
+            ms -= MAX_MS;
+            self.try_delay_ns(MAX_MS * 1_000_000)?;
+        }
+
+        self.try_delay_ns(ms * 1_000_000)
+    }
+
+    /// Pauses execution for at minimum `duration`
+    ///
+    /// Takes any of the unit types of the [`time`] module, e.g. `delay.try_delay(10.millis())`.
+    /// Pause can be longer if the implementation requires it due to precision/timing issues.
+    ///
+    /// [`time`]: ../../time/index.html
+    fn try_delay<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+        T: Into<Duration>,
+    {
+        // the largest number of nanoseconds that fits in `u32`
+        const MAX_NS: u32 = 0xFFFF_FFFF;
+
+        let mut ns = duration.into().as_nanos();
+
+        while ns > u64::from(MAX_NS) {
+            ns -= u64::from(MAX_NS);
+            self.try_delay_ns(MAX_NS)?;
+        }
+
+        self.try_delay_ns(ns as u32)
+    }
+}
+
+/// Delay provider built on a `CountDown` timer
+///
+/// HALs only need to implement `timer::CountDown` with a `Time` that can be created from a
+/// [`time::Duration`]; wrapping the timer in a `Delay` provides `DelayNs`, `DelayMs` and `DelayUs`.
+///
+/// `DelayMs` and `DelayUs` are only implemented for drivers that still require them. The methods
+/// of `DelayNs` have distinct names, so method calls stay unambiguous with all three in scope.
+///
+/// [`time::Duration`]: ../../time/struct.Duration.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::delay::Delay;
+/// use embedded_hal::prelude::*;
+///
+/// let timer: Timer6 = {
+///     // ..
+/// #   Timer6
+/// };
+///
+/// let mut delay = Delay::new(timer);
+/// delay.try_delay_millis(10).unwrap();
+/// // `DelayMs`, for drivers that still require it
+/// delay.try_delay_ms(10u8).unwrap();
+///
+/// // get the timer back
+/// let timer = delay.free();
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::time::Duration;
+/// # struct Timer6;
+/// # impl embedded_hal::timer::CountDown for Timer6 {
+/// #     type Error = Infallible;
+/// #     type Time = Duration;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Duration> { Ok(()) }
+/// #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+#[cfg(feature = "nb")]
+pub struct Delay<T> {
+    timer: T,
+}
+
+#[cfg(feature = "nb")]
+impl<T> Delay<T>
+where
+    T: CountDown,
+    Duration: Into<T::Time>,
+{
+    /// Creates a delay provider from a `CountDown` timer
+    pub fn new(timer: T) -> Self {
+        Delay { timer }
+    }
+
+    /// Releases the timer
+    pub fn free(self) -> T {
+        self.timer
+    }
+
+    fn delay(&mut self, duration: Duration) -> Result<(), T::Error> {
+        self.timer.try_start(duration)?;
+        block!(self.timer.try_wait())
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> DelayNs for Delay<T>
+where
+    T: CountDown,
+    Duration: Into<T::Time>,
+{
+    type Error = T::Error;
+
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        self.delay(Duration::from_nanos(u64::from(ns)))
+    }
+
+    fn try_delay_micros(&mut self, us: u32) -> Result<(), Self::Error> {
+        self.delay(Duration::from_micros(u64::from(us)))
+    }
+
+    fn try_delay_millis(&mut self, ms: u32) -> Result<(), Self::Error> {
+        self.delay(Duration::from_millis(u64::from(ms)))
+    }
+
+    fn try_delay<D>(&mut self, duration: D) -> Result<(), Self::Error>
+    where
+        D: Into<Duration>,
+    {
+        self.delay(duration.into())
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, UXX> DelayMs<UXX> for Delay<T>
+where
+    T: CountDown,
+    Duration: Into<T::Time>,
+    UXX: Into<u64>,
+{
+    type Error = T::Error;
+
+    fn try_delay_ms(&mut self, ms: UXX) -> Result<(), Self::Error> {
+        self.delay(Duration::from_millis(ms.into()))
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, UXX> DelayUs<UXX> for Delay<T>
+where
+    T: CountDown,
+    Duration: Into<T::Time>,
+    UXX: Into<u64>,
+{
+    type Error = T::Error;
+
+    fn try_delay_us(&mut self, us: UXX) -> Result<(), Self::Error> {
+        self.delay(Duration::from_micros(us.into()))
+    }
+}
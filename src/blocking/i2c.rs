@@ -1,12 +1,119 @@
 //! Blocking I2C API
 //!
-//! Slave addresses used by this API are 7-bit I2C addresses ranging from 0 to 127.
+//! This API supports 7-bit and 10-bit addresses. Traits feature an `AddressMode`
+//! marker type parameter. Two implementation of the `AddressMode` exist:
+//! `SevenBitAddress` and `TenBitAddress`.
 //!
-//! Operations on 10-bit slave addresses are not supported by the API yet (but applications might
-//! be able to emulate some operations).
+//! Through this marker types it is possible to implement each address mode for
+//! the traits independently in `embedded-hal` implementations and device drivers
+//! can depend only on the mode that they support.
+//!
+//! Additionally, the I2C 10-bit address mode has been developed to be fully
+//! backwards compatible with the 7-bit address mode. This allows for a
+//! software-emulated 10-bit addressing implementation if the address mode
+//! is not supported by the hardware.
+//!
+//! Since 7-bit addressing is the mode of the majority of I2C devices,
+//! `SevenBitAddress` has been set as default mode and thus can be omitted if desired.
+//!
+//! ## Examples
+//!
+//! ### `embedded-hal` implementation for an MCU
+//! Here is an example of an embedded-hal implementation of the `Write` trait
+//! for both modes:
+//! ```
+//! # use embedded_hal::blocking::i2c::{SevenBitAddress, TenBitAddress, Write};
+//! /// I2C0 hardware peripheral which supports both 7-bit and 10-bit addressing.
+//! pub struct I2c0;
+//!
+//! impl Write<SevenBitAddress> for I2c0
+//! {
+//! #   type Error = ();
+//! #
+//!     fn try_write(&mut self, addr: u8, output: &[u8]) -> Result<(), Self::Error> {
+//!         // ...
+//! #       Ok(())
+//!     }
+//! }
+//!
+//! impl Write<TenBitAddress> for I2c0
+//! {
+//! #   type Error = ();
+//! #
+//!     fn try_write(&mut self, addr: u16, output: &[u8]) -> Result<(), Self::Error> {
+//!         // ...
+//! #       Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! ### Device driver compatible only with 7-bit addresses
+//!
+//! For demonstration purposes the address mode parameter has been omitted in this example.
+//!
+//! ```
+//! # use embedded_hal::blocking::i2c::WriteRead;
+//! const ADDR: u8  = 0x15;
+//! # const TEMP_REGISTER: u8 = 0x1;
+//! pub struct TemperatureSensorDriver<I2C> {
+//!     i2c: I2C,
+//! }
+//!
+//! impl<I2C, E> TemperatureSensorDriver<I2C>
+//! where
+//!     I2C: WriteRead<Error = E>,
+//! {
+//!     pub fn read_temperature(&mut self) -> Result<u8, E> {
+//!         let mut temp = [0];
+//!         self.i2c
+//!             .try_write_read(ADDR, &[TEMP_REGISTER], &mut temp)
+//!             .and(Ok(temp[0]))
+//!     }
+//! }
+//! ```
+//!
+//! ### Device driver compatible only with 10-bit addresses
+//!
+//! ```
+//! # use embedded_hal::blocking::i2c::{TenBitAddress, WriteRead};
+//! const ADDR: u16  = 0x158;
+//! # const TEMP_REGISTER: u8 = 0x1;
+//! pub struct TemperatureSensorDriver<I2C> {
+//!     i2c: I2C,
+//! }
+//!
+//! impl<I2C, E> TemperatureSensorDriver<I2C>
+//! where
+//!     I2C: WriteRead<TenBitAddress, Error = E>,
+//! {
+//!     pub fn read_temperature(&mut self) -> Result<u8, E> {
+//!         let mut temp = [0];
+//!         self.i2c
+//!             .try_write_read(ADDR, &[TEMP_REGISTER], &mut temp)
+//!             .and(Ok(temp[0]))
+//!     }
+//! }
+//! ```
+
+use crate::private;
+
+/// Address mode (7-bit / 10-bit)
+///
+/// Note: This trait is sealed and should not be implemented outside of this crate.
+pub trait AddressMode: private::Sealed {}
+
+/// 7-bit address mode type
+pub type SevenBitAddress = u8;
+
+/// 10-bit address mode type
+pub type TenBitAddress = u16;
+
+impl AddressMode for SevenBitAddress {}
+
+impl AddressMode for TenBitAddress {}
 
 /// Blocking read
-pub trait Read {
+pub trait Read<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -28,11 +135,23 @@ pub trait Read {
     /// - `MAK` = master acknowledge
     /// - `NMAK` = master no acknowledge
     /// - `SP` = stop condition
-    fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error>;
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<A, T> Read<A> for &mut T
+where
+    A: AddressMode,
+    T: Read<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        T::try_read(self, address, buffer)
+    }
 }
 
 /// Blocking write
-pub trait Write {
+pub trait Write<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -52,11 +171,36 @@ pub trait Write {
     /// - `SAK` = slave acknowledge
     /// - `Bi` = ith byte of data
     /// - `SP` = stop condition
-    fn try_write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error>;
+    fn try_write(&mut self, addr: A, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<A, T> Write<A> for &mut T
+where
+    A: AddressMode,
+    T: Write<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, addr: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        T::try_write(self, addr, bytes)
+    }
 }
 
 /// Blocking write (iterator version)
-pub trait WriteIter {
+///
+/// # Examples
+///
+/// Prepend a register address to a payload without copying both into a temporary buffer
+///
+/// ```
+/// use core::iter;
+/// use embedded_hal::blocking::i2c::WriteIter;
+///
+/// fn write_page<I: WriteIter>(i2c: &mut I, register: u8, page: &[u8]) -> Result<(), I::Error> {
+///     i2c.try_write_iter(0x50, iter::once(register).chain(page.iter().cloned()))
+/// }
+/// ```
+pub trait WriteIter<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -65,13 +209,28 @@ pub trait WriteIter {
     /// # I2C Events (contract)
     ///
     /// Same as `Write`
-    fn try_write<B>(&mut self, addr: u8, bytes: B) -> Result<(), Self::Error>
+    fn try_write_iter<B>(&mut self, addr: A, bytes: B) -> Result<(), Self::Error>
     where
         B: IntoIterator<Item = u8>;
 }
 
+impl<A, T> WriteIter<A> for &mut T
+where
+    A: AddressMode,
+    T: WriteIter<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write_iter<B>(&mut self, addr: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        T::try_write_iter(self, addr, bytes)
+    }
+}
+
 /// Blocking write + read
-pub trait WriteRead {
+pub trait WriteRead<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -99,14 +258,46 @@ pub trait WriteRead {
     /// - `SP` = stop condition
     fn try_write_read(
         &mut self,
-        address: u8,
+        address: A,
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error>;
 }
 
+impl<A, T> WriteRead<A> for &mut T
+where
+    A: AddressMode,
+    T: WriteRead<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        T::try_write_read(self, address, bytes, buffer)
+    }
+}
+
 /// Blocking write (iterator version) + read
-pub trait WriteIterRead {
+///
+/// # Examples
+///
+/// Send a multi-byte register address followed by a read without building an intermediate buffer
+///
+/// ```
+/// use embedded_hal::blocking::i2c::WriteIterRead;
+///
+/// fn read_word<I: WriteIterRead>(i2c: &mut I, register: u16) -> Result<u16, I::Error> {
+///     let mut buffer = [0; 2];
+///     i2c.try_write_iter_read(0x50, register.to_be_bytes().iter().cloned(), &mut buffer)?;
+///
+///     Ok(u16::from_be_bytes(buffer))
+/// }
+/// ```
+pub trait WriteIterRead<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -118,10 +309,259 @@ pub trait WriteIterRead {
     /// Same as the `WriteRead` trait
     fn try_write_iter_read<B>(
         &mut self,
-        address: u8,
+        address: A,
         bytes: B,
         buffer: &mut [u8],
     ) -> Result<(), Self::Error>
     where
         B: IntoIterator<Item = u8>;
 }
+
+impl<A, T> WriteIterRead<A> for &mut T
+where
+    A: AddressMode,
+    T: WriteIterRead<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        T::try_write_iter_read(self, address, bytes, buffer)
+    }
+}
+
+/// Transactional I2C operation
+///
+/// Several operations can be combined as part of a transaction.
+#[derive(Debug, PartialEq)]
+pub enum Operation<'a> {
+    /// Read data into the provided buffer
+    Read(&'a mut [u8]),
+    /// Write data from the provided buffer
+    Write(&'a [u8]),
+}
+
+/// Transactional I2C interface
+///
+/// This allows combining operations within an I2C transaction.
+///
+/// # Examples
+///
+/// Write a register address and read back its value, then write a second register, without
+/// releasing the bus in between
+///
+/// ```
+/// use embedded_hal::blocking::i2c::{Operation, Transactional};
+///
+/// fn read_then_ack<I: Transactional>(i2c: &mut I) -> Result<u8, I::Error> {
+///     let mut status = [0];
+///     i2c.try_exec(
+///         0x42,
+///         &mut [
+///             Operation::Write(&[0x0F]),
+///             Operation::Read(&mut status),
+///             Operation::Write(&[0x10, 0x01]),
+///         ],
+///     )?;
+///
+///     Ok(status[0])
+/// }
+/// ```
+pub trait Transactional<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Executes the provided operations on the I2C bus as a single transaction
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// - Before executing the first operation an `ST` is sent automatically. This is followed by
+    ///   `SAD+R/W` as appropriate.
+    /// - Data from adjacent operations of the same type are sent after each other without an `SP`
+    ///   or `SR`.
+    /// - Between adjacent operations of a different type an `SR` and `SAD+R/W` is sent.
+    /// - After executing the last operation an `SP` is sent automatically.
+    /// - If the last operation is a `Read` the master does not send an acknowledge for the last
+    ///   byte.
+    ///
+    /// Where
+    ///
+    /// - `ST` = start condition
+    /// - `SAD+R/W` = slave address followed by bit 1 to indicate reading or 0 to indicate writing
+    /// - `SR` = repeated start condition
+    /// - `SP` = stop condition
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<A, T> Transactional<A> for &mut T
+where
+    A: AddressMode,
+    T: Transactional<A> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        T::try_exec(self, address, operations)
+    }
+}
+
+/// Blocking I2C master
+///
+/// This trait combines `Read`, `Write`, `WriteRead` and `Transactional` with a single error type
+/// so that drivers only need one bound. It is implemented for every type that implements those
+/// four traits with the same error type.
+///
+/// *NOTE* The supertraits also declare an `Error` associated type, so the error type must be
+/// named with the fully qualified syntax, i.e. `<I as I2c>::Error`.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::i2c::I2c;
+///
+/// const ADDRESS: u8 = 0x48;
+///
+/// fn read_temperature<I: I2c>(i2c: &mut I) -> Result<i16, <I as I2c>::Error> {
+///     let mut data = [0; 2];
+///     i2c.try_write(ADDRESS, &[0x01, 0x60])?;
+///     i2c.try_write_read(ADDRESS, &[0x00], &mut data)?;
+///     Ok(i16::from_be_bytes(data) >> 4)
+/// }
+/// ```
+pub trait I2c<A: AddressMode = SevenBitAddress>:
+    Read<A, Error = <Self as I2c<A>>::Error>
+    + Write<A, Error = <Self as I2c<A>>::Error>
+    + WriteRead<A, Error = <Self as I2c<A>>::Error>
+    + Transactional<A, Error = <Self as I2c<A>>::Error>
+{
+    /// Error type shared by all the I2C operations
+    type Error;
+}
+
+impl<A, I, E> I2c<A> for I
+where
+    A: AddressMode,
+    I: Read<A, Error = E>
+        + Write<A, Error = E>
+        + WriteRead<A, Error = E>
+        + Transactional<A, Error = E>,
+{
+    type Error = E;
+}
+
+/// Register-oriented read
+///
+/// Most I2C devices expose their state as a register map: the master writes the address of the
+/// register and then reads back its contents. This trait is implemented for every `WriteRead`
+/// implementer.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::i2c::RegisterRead;
+///
+/// const ADDRESS: u8 = 0x1E;
+/// const OUT_X_H: u8 = 0x03;
+///
+/// fn magnetic_field<I: RegisterRead>(i2c: &mut I) -> Result<[u8; 6], I::Error> {
+///     let mut data = [0; 6];
+///     i2c.try_read_register(ADDRESS, OUT_X_H, &mut data)?;
+///     Ok(data)
+/// }
+/// ```
+pub trait RegisterRead<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Reads enough bytes from the `register` of the slave with address `address` to fill
+    /// `buffer`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the `WriteRead` trait, with `register` as the only outgoing byte
+    fn try_read_register(
+        &mut self,
+        address: A,
+        register: u8,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<A, I> RegisterRead<A> for I
+where
+    A: AddressMode,
+    I: WriteRead<A>,
+{
+    type Error = I::Error;
+
+    fn try_read_register(
+        &mut self,
+        address: A,
+        register: u8,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.try_write_read(address, &[register], buffer)
+    }
+}
+
+/// Register-oriented write
+///
+/// This trait is implemented for every `Write` implementer; the register address and the payload
+/// are copied into a buffer on the stack, to be sent in a single write.
+pub trait RegisterWrite<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Writes `bytes` to the `register` of the slave with address `address`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the `Write` trait, with `register` sent before `bytes`
+    ///
+    /// # Panics
+    ///
+    /// The implementation for `Write` implementers panics if `bytes` is longer than 31 bytes.
+    fn try_write_register(
+        &mut self,
+        address: A,
+        register: u8,
+        bytes: &[u8],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<A, I> RegisterWrite<A> for I
+where
+    A: AddressMode,
+    I: Write<A>,
+{
+    type Error = I::Error;
+
+    fn try_write_register(
+        &mut self,
+        address: A,
+        register: u8,
+        bytes: &[u8],
+    ) -> Result<(), Self::Error> {
+        let mut buffer = [0; 32];
+        assert!(bytes.len() < buffer.len(), "register payload too long");
+
+        buffer[0] = register;
+        buffer[1..=bytes.len()].copy_from_slice(bytes);
+        self.try_write(address, &buffer[..=bytes.len()])
+    }
+}
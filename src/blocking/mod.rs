@@ -1,11 +1,45 @@
 //! Blocking API
 //!
 //! In some cases it's possible to implement these blocking traits on top of one of the core HAL
-//! traits. To save boilerplate when that's the case a `Default` marker trait may be provided.
-//! Implementing that marker trait will opt in your type into a blanket implementation.
+//! traits. To save boilerplate when that's the case a wrapper type is provided, e.g.
+//! [`BlockingSpi`] implements the blocking SPI traits for any `spi::FullDuplex` implementation.
+//! HALs with a better blocking implementation are free to implement the traits directly instead.
+//!
+//! [`BlockingSpi`]: spi/struct.BlockingSpi.html
+//!
+//! The I2C, SPI and serial traits are implemented for `&mut T`, so a bus can be lent to a driver
+//! and used again once the driver is dropped.
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::Write;
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//!
+//! struct Display<I> {
+//!     i2c: I,
+//! }
+//!
+//! impl<I: Write> Display<I> {
+//!     fn clear(&mut self) -> Result<(), I::Error> {
+//!         self.i2c.try_write(0x3C, &[0x00, 0x01])
+//!     }
+//! }
+//!
+//! let mut i2c = I2c;
+//!
+//! Display { i2c: &mut i2c }.clear().unwrap();
+//! i2c.try_write(0x48, &[0x01, 0x60]).unwrap();
+//! ```
 
 pub mod delay;
 pub mod i2c;
+#[cfg(all(feature = "nb", feature = "qei"))]
+pub mod qei;
+#[cfg(feature = "rng")]
 pub mod rng;
 pub mod serial;
 pub mod spi;
@@ -0,0 +1,168 @@
+//! Quadrature encoder speed measurement
+
+use core::convert::TryFrom;
+use core::mem;
+
+use crate::qei::Qei;
+use crate::time::Duration;
+use crate::timer::CountDown;
+
+/// Speed measurement error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error<QE, TE> {
+    /// Error reported by the encoder
+    Qei(QE),
+    /// Error reported by the timer
+    Timer(TE),
+    /// The speed doesn't fit in an `i64`
+    Overflow,
+}
+
+/// Measures the speed of a quadrature encoder with a `CountDown` timer
+///
+/// The encoder count is allowed to wrap around during the measurement, as long as the encoder
+/// moves by less than half the range of `Count` in either direction.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::qei::Velocity;
+/// use embedded_hal::time::U32Ext;
+///
+/// let qei: Qei1 = {
+///     // ..
+/// #   Qei1 { counts: core::cell::Cell::new([4, 65_530]) }
+/// };
+/// let timer: Timer6 = {
+///     // ..
+/// #   Timer6
+/// };
+///
+/// let mut velocity = Velocity::new(qei, timer);
+///
+/// // the count went from 65530 to 4 (10 counts) in 100 ms
+/// let speed = velocity.try_measure(100.millis()).unwrap();
+/// assert_eq!(speed, 100);
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::time::Duration;
+/// # struct Qei1 { counts: core::cell::Cell<[u16; 2]> }
+/// # impl embedded_hal::qei::Qei for Qei1 {
+/// #     type Error = Infallible;
+/// #     type Count = u16;
+/// #     fn try_count(&self) -> Result<u16, Infallible> {
+/// #         let [next, count] = self.counts.get();
+/// #         self.counts.set([next, next]);
+/// #         Ok(count)
+/// #     }
+/// #     fn try_direction(&self) -> Result<embedded_hal::qei::Direction, Infallible> { unimplemented!() }
+/// # }
+/// # struct Timer6;
+/// # impl embedded_hal::timer::CountDown for Timer6 {
+/// #     type Error = Infallible;
+/// #     type Time = Duration;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Duration> { Ok(()) }
+/// #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+///
+/// Moving backwards across zero
+///
+/// ```
+/// # use embedded_hal::blocking::qei::Velocity;
+/// # use embedded_hal::time::U32Ext;
+/// # let qei = Qei1 { counts: core::cell::Cell::new([65_526, 10]) };
+/// # let timer = Timer6;
+/// let mut velocity = Velocity::new(qei, timer);
+///
+/// // the count went from 10 to 65526 (-20 counts) in 1 s
+/// assert_eq!(velocity.try_measure(1.secs()).unwrap(), -20);
+/// # use core::convert::Infallible;
+/// # use embedded_hal::time::Duration;
+/// # struct Qei1 { counts: core::cell::Cell<[u16; 2]> }
+/// # impl embedded_hal::qei::Qei for Qei1 {
+/// #     type Error = Infallible;
+/// #     type Count = u16;
+/// #     fn try_count(&self) -> Result<u16, Infallible> {
+/// #         let [next, count] = self.counts.get();
+/// #         self.counts.set([next, next]);
+/// #         Ok(count)
+/// #     }
+/// #     fn try_direction(&self) -> Result<embedded_hal::qei::Direction, Infallible> { unimplemented!() }
+/// # }
+/// # struct Timer6;
+/// # impl embedded_hal::timer::CountDown for Timer6 {
+/// #     type Error = Infallible;
+/// #     type Time = Duration;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Duration> { Ok(()) }
+/// #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+pub struct Velocity<Q, T> {
+    qei: Q,
+    timer: T,
+}
+
+impl<Q, T> Velocity<Q, T>
+where
+    Q: Qei,
+    Q::Count: Into<i64>,
+    T: CountDown,
+    Duration: Into<T::Time>,
+{
+    /// Creates a speed meter from an encoder and a timer
+    pub fn new(qei: Q, timer: T) -> Self {
+        Velocity { qei, timer }
+    }
+
+    /// Releases the encoder and the timer
+    pub fn free(self) -> (Q, T) {
+        (self.qei, self.timer)
+    }
+
+    /// Counts the encoder pulses during `gate` and returns the speed in counts per second
+    ///
+    /// Blocks for `gate`. The speed is negative when the encoder counts down.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the speed doesn't fit in an `i64`, which can only happen with
+    /// counts wider than 32 bits and a gate time of less than a second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gate` is zero.
+    pub fn try_measure<D>(&mut self, gate: D) -> Result<i64, Error<Q::Error, T::Error>>
+    where
+        D: Into<Duration>,
+    {
+        let gate = gate.into();
+
+        let before = self.qei.try_count().map_err(Error::Qei)?;
+        self.timer.try_start(gate).map_err(Error::Timer)?;
+        block!(self.timer.try_wait()).map_err(Error::Timer)?;
+        let after = self.qei.try_count().map_err(Error::Qei)?;
+
+        let delta = wrapping_delta::<Q::Count>(before.into(), after.into());
+        let speed = i128::from(delta) * 1_000_000_000 / i128::from(gate.as_nanos());
+        i64::try_from(speed).map_err(|_| Error::Overflow)
+    }
+}
+
+/// Difference `after - before` of two counts that may have wrapped around
+fn wrapping_delta<C>(before: i64, after: i64) -> i64 {
+    let bits = 8 * mem::size_of::<C>() as u32;
+    if bits >= 64 {
+        return after.wrapping_sub(before);
+    }
+
+    let range = 1_i64 << bits;
+    let delta = (after - before) & (range - 1);
+
+    // the shortest way around
+    if delta >= range / 2 {
+        delta - range
+    } else {
+        delta
+    }
+}
@@ -1,5 +1,11 @@
 //! Blocking serial API
 
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "nb")]
+use crate::serial;
+
 /// Write half of a serial interface (blocking variant)
 pub trait Write<Word> {
     /// The type of error that can occur when writing
@@ -17,38 +23,186 @@ pub trait Write<Word> {
 
     /// Block until the serial interface has sent all buffered words
     fn try_bflush(&mut self) -> Result<(), Self::Error>;
-}
 
-/// Blocking serial write
-pub mod write {
-    /// Marker trait to opt into default blocking write implementation
+    /// Writes formatted text, blocking until everything has been written
     ///
-    /// Implementers of [`serial::Write`] can implement this marker trait
-    /// for their type. Doing so will automatically provide the default
-    /// implementation of [`blocking::serial::Write`] for the type.
+    /// Usually called with `format_args!`. Like `try_bwrite_all`, the text may only have been
+    /// buffered when this returns. An error returned by the `Display` implementation of an
+    /// argument stops the output, but is not reported since it can't be converted to
+    /// `Self::Error`.
     ///
-    /// [`serial::Write`]: ../../serial/trait.Write.html
-    /// [`blocking::serial::Write`]: ../trait.Write.html
-    pub trait Default<Word>: crate::serial::Write<Word> {}
-
-    impl<S, Word> crate::blocking::serial::Write<Word> for S
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_hal::blocking::serial::Write;
+    /// # use core::convert::Infallible;
+    /// # struct Usart1;
+    /// # impl Write<u8> for Usart1 {
+    /// #     type Error = Infallible;
+    /// #     fn try_bwrite_all(&mut self, _: &[u8]) -> Result<(), Infallible> { Ok(()) }
+    /// #     fn try_bflush(&mut self) -> Result<(), Infallible> { Ok(()) }
+    /// # }
+    ///
+    /// let mut serial = Usart1;
+    /// let temperature = 21;
+    ///
+    /// serial.try_write_fmt(format_args!("T = {} C\r\n", temperature)).unwrap();
+    /// ```
+    fn try_write_fmt(&mut self, args: fmt::Arguments) -> Result<(), Self::Error>
     where
-        S: Default<Word>,
-        Word: Clone,
+        Word: Copy + From<u8>,
     {
-        type Error = S::Error;
+        let mut adapter = FmtAdapter {
+            serial: self,
+            error: None,
+            _word: PhantomData,
+        };
+        let _ = fmt::write(&mut adapter, args);
+
+        match adapter.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
 
-        fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
-            for word in buffer {
-                block!(self.try_write(word.clone()))?;
+/// `core::fmt::Write` adapter used by `try_write_fmt`, keeping the serial error
+struct FmtAdapter<'a, S, Word>
+where
+    S: Write<Word> + ?Sized,
+{
+    serial: &'a mut S,
+    error: Option<S::Error>,
+    _word: PhantomData<Word>,
+}
+
+impl<'a, S, Word> fmt::Write for FmtAdapter<'a, S, Word>
+where
+    S: Write<Word> + ?Sized,
+    Word: Copy + From<u8>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // converts the text by chunks, writing each one with a single call
+        let mut buffer = [Word::from(0); 32];
+
+        for chunk in s.as_bytes().chunks(buffer.len()) {
+            for (word, &byte) in buffer.iter_mut().zip(chunk) {
+                *word = Word::from(byte);
             }
 
-            Ok(())
+            if let Err(error) = self.serial.try_bwrite_all(&buffer[..chunk.len()]) {
+                self.error = Some(error);
+                return Err(fmt::Error);
+            }
         }
 
-        fn try_bflush(&mut self) -> Result<(), Self::Error> {
-            block!(self.try_flush())?;
-            Ok(())
+        Ok(())
+    }
+}
+
+impl<S, Word> Write<Word> for &mut S
+where
+    S: Write<Word> + ?Sized,
+{
+    type Error = S::Error;
+
+    fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
+        S::try_bwrite_all(self, buffer)
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        S::try_bflush(self)
+    }
+}
+
+/// Blocking serial write on top of a [`serial::Write`] implementation
+///
+/// Implements [`blocking::serial::Write`] by writing one word at a time, blocking on each.
+/// The non-blocking serial traits of the wrapped implementation remain available.
+///
+/// [`serial::Write`]: ../../serial/trait.Write.html
+/// [`blocking::serial::Write`]: trait.Write.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::serial::{BlockingSerial, Write};
+/// # use core::convert::Infallible;
+/// # struct Usart1;
+/// # impl embedded_hal::serial::Write<u8> for Usart1 {
+/// #     type Error = Infallible;
+/// #     fn try_write(&mut self, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// #     fn try_flush(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+///
+/// let mut serial = BlockingSerial::new(Usart1);
+///
+/// serial.try_bwrite_all(b"Hello, world!\r\n").unwrap();
+/// serial.try_bflush().unwrap();
+/// ```
+#[cfg(feature = "nb")]
+pub struct BlockingSerial<T> {
+    serial: T,
+}
+
+#[cfg(feature = "nb")]
+impl<T> BlockingSerial<T> {
+    /// Wraps the non-blocking `serial`
+    pub fn new(serial: T) -> Self {
+        BlockingSerial { serial }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> T {
+        self.serial
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, Word> serial::Read<Word> for BlockingSerial<T>
+where
+    T: serial::Read<Word>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        self.serial.try_read()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, Word> serial::Write<Word> for BlockingSerial<T>
+where
+    T: serial::Write<Word>,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        self.serial.try_write(word)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.serial.try_flush()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, Word> Write<Word> for BlockingSerial<T>
+where
+    T: serial::Write<Word>,
+    Word: Clone,
+{
+    type Error = T::Error;
+
+    fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
+        for word in buffer {
+            block!(self.serial.try_write(word.clone()))?;
         }
+
+        Ok(())
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        block!(self.serial.try_flush())
     }
 }
@@ -1,5 +1,8 @@
 //! Blocking SPI API
 
+#[cfg(feature = "nb")]
+use crate::spi::FullDuplex;
+
 /// Blocking transfer
 pub trait Transfer<W> {
     /// Error type
@@ -9,6 +12,17 @@ pub trait Transfer<W> {
     fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], Self::Error>;
 }
 
+impl<T, W> Transfer<W> for &mut T
+where
+    T: Transfer<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], Self::Error> {
+        T::try_transfer(self, words)
+    }
+}
+
 /// Blocking write
 pub trait Write<W> {
     /// Error type
@@ -18,6 +32,17 @@ pub trait Write<W> {
     fn try_write(&mut self, words: &[W]) -> Result<(), Self::Error>;
 }
 
+impl<T, W> Write<W> for &mut T
+where
+    T: Write<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+        T::try_write(self, words)
+    }
+}
+
 /// Blocking write (iterator version)
 pub trait WriteIter<W> {
     /// Error type
@@ -29,76 +54,260 @@ pub trait WriteIter<W> {
         WI: IntoIterator<Item = W>;
 }
 
-/// Blocking transfer
-pub mod transfer {
-    /// Default implementation of `blocking::spi::Transfer<W>` for implementers of
-    /// `spi::FullDuplex<W>`
-    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+impl<T, W> WriteIter<W> for &mut T
+where
+    T: WriteIter<W> + ?Sized,
+{
+    type Error = T::Error;
 
-    impl<W, S> crate::blocking::spi::Transfer<W> for S
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
     where
-        S: Default<W>,
-        W: Clone,
+        WI: IntoIterator<Item = W>,
     {
-        type Error = S::Error;
+        T::try_write_iter(self, words)
+    }
+}
 
-        fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], S::Error> {
-            for word in words.iter_mut() {
-                block!(self.try_send(word.clone()))?;
-                *word = block!(self.try_read())?;
-            }
+/// Transactional SPI operation
+///
+/// Several operations can be combined as part of a transaction.
+#[derive(Debug, PartialEq)]
+pub enum Operation<'a, W: 'static> {
+    /// Write data from the provided buffer, discarding read data
+    Write(&'a [W]),
+    /// Write data out while reading data into the provided buffer
+    Transfer(&'a mut [W]),
+}
 
-            Ok(words)
-        }
+/// Transactional SPI interface
+///
+/// This allows combining operations within an SPI transaction.
+pub trait Transactional<W: 'static> {
+    /// Associated error type
+    type Error;
+
+    /// Executes the provided transactions
+    ///
+    /// It's the task of the user of this interface to manage the slave select line around the
+    /// whole transaction.
+    fn try_exec<'a>(&mut self, operations: &mut [Operation<'a, W>]) -> Result<(), Self::Error>;
+}
+
+impl<T, W> Transactional<W> for &mut T
+where
+    T: Transactional<W> + ?Sized,
+    W: 'static,
+{
+    type Error = T::Error;
+
+    fn try_exec<'a>(&mut self, operations: &mut [Operation<'a, W>]) -> Result<(), Self::Error> {
+        T::try_exec(self, operations)
     }
 }
 
-/// Blocking write
-pub mod write {
-    /// Default implementation of `blocking::spi::Write<W>` for implementers of `spi::FullDuplex<W>`
-    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+/// Register-oriented read
+///
+/// This trait is implemented for every `Transactional` SPI interface.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::spi::RegisterRead;
+///
+/// // many devices set the MSB of the register address to signal a read
+/// const READ: u8 = 0x80;
+/// const WHO_AM_I: u8 = 0x0F;
+///
+/// fn who_am_i<S: RegisterRead<u8>>(spi: &mut S) -> Result<u8, S::Error> {
+///     let mut id = [0];
+///     spi.try_read_register(READ | WHO_AM_I, &mut id)?;
+///     Ok(id[0])
+/// }
+/// ```
+pub trait RegisterRead<W> {
+    /// Error type
+    type Error;
 
-    impl<W, S> crate::blocking::spi::Write<W> for S
-    where
-        S: Default<W>,
-        W: Clone,
-    {
-        type Error = S::Error;
+    /// Sends the `register` address and then clocks in enough words to fill `buffer`
+    ///
+    /// `register` is sent as is; device specific read / write flags must be applied by the
+    /// caller. The current contents of `buffer` are sent while reading.
+    fn try_read_register(&mut self, register: W, buffer: &mut [W]) -> Result<(), Self::Error>;
+}
 
-        fn try_write(&mut self, words: &[W]) -> Result<(), S::Error> {
-            for word in words {
-                block!(self.try_send(word.clone()))?;
-                block!(self.try_read())?;
-            }
+impl<W, S> RegisterRead<W> for S
+where
+    S: Transactional<W>,
+    W: Copy + 'static,
+{
+    type Error = S::Error;
+
+    fn try_read_register(&mut self, register: W, buffer: &mut [W]) -> Result<(), Self::Error> {
+        self.try_exec(&mut [Operation::Write(&[register]), Operation::Transfer(buffer)])
+    }
+}
+
+/// Register-oriented write
+///
+/// This trait is implemented for every `Transactional` SPI interface.
+pub trait RegisterWrite<W> {
+    /// Error type
+    type Error;
+
+    /// Sends the `register` address followed by `words`
+    ///
+    /// `register` is sent as is; device specific read / write flags must be applied by the
+    /// caller.
+    fn try_write_register(&mut self, register: W, words: &[W]) -> Result<(), Self::Error>;
+}
+
+impl<W, S> RegisterWrite<W> for S
+where
+    S: Transactional<W>,
+    W: Copy + 'static,
+{
+    type Error = S::Error;
+
+    fn try_write_register(&mut self, register: W, words: &[W]) -> Result<(), Self::Error> {
+        self.try_exec(&mut [Operation::Write(&[register]), Operation::Write(words)])
+    }
+}
+
+/// Blocking SPI on top of a [`FullDuplex`] implementation
+///
+/// Implements all the blocking SPI traits by sending each word and reading back the word received
+/// in exchange. HALs whose peripheral can't do better than that wrap their `FullDuplex`
+/// implementation in `BlockingSpi`; HALs with a faster blocking implementation (e.g. using a FIFO
+/// or DMA) implement the blocking traits directly.
+///
+/// [`FullDuplex`]: ../../spi/trait.FullDuplex.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::spi::{BlockingSpi, Transfer};
+/// # use core::convert::Infallible;
+/// # struct Spi1;
+/// # impl embedded_hal::spi::FullDuplex<u8> for Spi1 {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self) -> nb::Result<u8, Infallible> { Ok(0xFF) }
+/// #     fn try_send(&mut self, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+///
+/// let mut spi = BlockingSpi::new(Spi1);
+///
+/// let mut words = [0x9F, 0x00, 0x00];
+/// assert_eq!(spi.try_transfer(&mut words).unwrap(), &[0xFF, 0xFF, 0xFF]);
+///
+/// let _spi1: Spi1 = spi.free();
+/// ```
+#[cfg(feature = "nb")]
+pub struct BlockingSpi<T> {
+    spi: T,
+}
+
+#[cfg(feature = "nb")]
+impl<T> BlockingSpi<T> {
+    /// Wraps the non-blocking `spi`
+    pub fn new(spi: T) -> Self {
+        BlockingSpi { spi }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> T {
+        self.spi
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, W> FullDuplex<W> for BlockingSpi<T>
+where
+    T: FullDuplex<W>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        self.spi.try_read()
+    }
+
+    fn try_send(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        self.spi.try_send(word)
+    }
+}
 
-            Ok(())
+#[cfg(feature = "nb")]
+impl<T, W> Transfer<W> for BlockingSpi<T>
+where
+    T: FullDuplex<W>,
+    W: Clone,
+{
+    type Error = T::Error;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], T::Error> {
+        for word in words.iter_mut() {
+            block!(self.spi.try_send(word.clone()))?;
+            *word = block!(self.spi.try_read())?;
         }
+
+        Ok(words)
     }
 }
 
-/// Blocking write (iterator version)
-pub mod write_iter {
-    /// Default implementation of `blocking::spi::WriteIter<W>` for implementers of
-    /// `spi::FullDuplex<W>`
-    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+#[cfg(feature = "nb")]
+impl<T, W> Write<W> for BlockingSpi<T>
+where
+    T: FullDuplex<W>,
+    W: Clone,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, words: &[W]) -> Result<(), T::Error> {
+        for word in words {
+            block!(self.spi.try_send(word.clone()))?;
+            block!(self.spi.try_read())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, W> WriteIter<W> for BlockingSpi<T>
+where
+    T: FullDuplex<W>,
+    W: Clone,
+{
+    type Error = T::Error;
 
-    impl<W, S> crate::blocking::spi::WriteIter<W> for S
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), T::Error>
     where
-        S: Default<W>,
-        W: Clone,
+        WI: IntoIterator<Item = W>,
     {
-        type Error = S::Error;
-
-        fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), S::Error>
-        where
-            WI: IntoIterator<Item = W>,
-        {
-            for word in words.into_iter() {
-                block!(self.try_send(word.clone()))?;
-                block!(self.try_read())?;
-            }
+        for word in words.into_iter() {
+            block!(self.spi.try_send(word.clone()))?;
+            block!(self.spi.try_read())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, W> Transactional<W> for BlockingSpi<T>
+where
+    T: FullDuplex<W>,
+    W: Clone + 'static,
+{
+    type Error = T::Error;
 
-            Ok(())
+    fn try_exec<'a>(&mut self, operations: &mut [Operation<'a, W>]) -> Result<(), T::Error> {
+        for op in operations {
+            match op {
+                Operation::Write(w) => self.try_write(w)?,
+                Operation::Transfer(t) => self.try_transfer(t).map(|_| ())?,
+            }
         }
+
+        Ok(())
     }
 }
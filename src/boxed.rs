@@ -0,0 +1,1070 @@
+//! Implementations of the traits for `Box<T>`
+//!
+//! Traits provided through a default implementation marker, e.g. `digital::toggleable`, are
+//! forwarded by forwarding the marker: `Box<T>` gets the default implementation if `T` opted into
+//! it. Traits with a blanket implementation, like `blocking::i2c::I2c`, the `RegisterRead` /
+//! `RegisterWrite` traits or `storage::Storage` for EEPROMs, follow from the forwarded traits.
+//!
+//! Besides the digital, serial, `io`, SPI, I2C, delay and RNG traits, `Box<T>` implements:
+//!
+//! - `timer::CountDown` and its extension traits, `timer::Clock` and `timer::Alarm`
+//! - `adc::OneShot`, `capture::Capture`, `pwm::Pwm`, `pwm::PwmPin`, `qei::Qei` and
+//!   `watchdog::Watchdog`
+//! - `can::Configure`, `can::Mailboxes` and `rtc::Calibrate`
+//! - the `NorFlash`, `Eeprom` and `Otp` storage traits
+//! - `power::WakeupSource`, `power::BrownOut`, `power::FrequencyScaling` and
+//!   `power::FrequencyListener`
+//! - `net::TcpClient`, `net::UdpClient` and `net::Dns`
+//!
+//! The other traits aren't implemented, in particular the DMA traits and `dma::Transfer`, which
+//! consume the implementation, and the configuration traits, usually used before the
+//! implementation is boxed.
+//!
+//! ```
+//! use embedded_hal::digital::OutputPin;
+//! # use core::convert::Infallible;
+//! # struct Led;
+//! # struct Relay;
+//! # impl OutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! # impl OutputPin for Relay {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//!
+//! fn all_off<P: OutputPin>(pins: &mut [P]) -> Result<(), P::Error> {
+//!     for pin in pins {
+//!         pin.try_set_low()?;
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let mut outputs: Vec<Box<dyn OutputPin<Error = Infallible>>> =
+//!     vec![Box::new(Led), Box::new(Relay)];
+//! all_off(&mut outputs).unwrap();
+//! ```
+
+use alloc::boxed::Box;
+#[cfg(feature = "pwm")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "adc")]
+use crate::adc::{self, OneShot};
+use crate::blocking;
+use crate::blocking::{delay, i2c};
+#[cfg(feature = "can")]
+use crate::can;
+#[cfg(feature = "capture")]
+use crate::capture::Capture;
+use crate::digital::{toggleable, InputPin, OutputPin, StatefulOutputPin};
+#[cfg(feature = "net")]
+use crate::net::{self, AddressFamily, IpAddr, SocketAddr};
+#[cfg(feature = "power")]
+use crate::power::{self, FrequencyListener};
+#[cfg(feature = "pwm")]
+use crate::pwm::{Pwm, PwmPin};
+#[cfg(feature = "qei")]
+use crate::qei::{Direction, Qei};
+#[cfg(feature = "rng")]
+use crate::rng;
+#[cfg(feature = "rtc")]
+use crate::rtc;
+#[cfg(feature = "nb")]
+use crate::spi::FullDuplex;
+#[cfg(feature = "storage")]
+use crate::storage::{eeprom::Eeprom, nor_flash, otp::Otp};
+#[cfg(feature = "power")]
+use crate::time::Hertz;
+use crate::timer;
+#[cfg(feature = "nb")]
+use crate::timer::CountDown;
+#[cfg(feature = "watchdog")]
+use crate::watchdog::Watchdog;
+#[cfg(feature = "nb")]
+use crate::{io, serial};
+
+impl<T> OutputPin for Box<T>
+where
+    T: OutputPin + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        (**self).try_set_low()
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        (**self).try_set_high()
+    }
+}
+
+impl<T> StatefulOutputPin for Box<T>
+where
+    T: StatefulOutputPin + ?Sized,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        (**self).try_is_set_high()
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        (**self).try_is_set_low()
+    }
+}
+
+impl<T> toggleable::Default for Box<T> where T: toggleable::Default + ?Sized {}
+
+impl<T> InputPin for Box<T>
+where
+    T: InputPin + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        (**self).try_is_high()
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        (**self).try_is_low()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, Word> serial::Read<Word> for Box<T>
+where
+    T: serial::Read<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        (**self).try_read()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, Word> serial::Write<Word> for Box<T>
+where
+    T: serial::Write<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        (**self).try_write(word)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        (**self).try_flush()
+    }
+}
+
+impl<T, Word> blocking::serial::Write<Word> for Box<T>
+where
+    T: blocking::serial::Write<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
+        (**self).try_bwrite_all(buffer)
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        (**self).try_bflush()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> io::Read for Box<T>
+where
+    T: io::Read + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error> {
+        (**self).try_read(buf)
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> io::Write for Box<T>
+where
+    T: io::Write + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error> {
+        (**self).try_write(buf)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        (**self).try_flush()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T, W> FullDuplex<W> for Box<T>
+where
+    T: FullDuplex<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        (**self).try_read()
+    }
+
+    fn try_send(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        (**self).try_send(word)
+    }
+}
+
+impl<T, W> blocking::spi::Transfer<W> for Box<T>
+where
+    T: blocking::spi::Transfer<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], Self::Error> {
+        (**self).try_transfer(words)
+    }
+}
+
+impl<T, W> blocking::spi::Write<W> for Box<T>
+where
+    T: blocking::spi::Write<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+        (**self).try_write(words)
+    }
+}
+
+impl<T, W> blocking::spi::WriteIter<W> for Box<T>
+where
+    T: blocking::spi::WriteIter<W> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = W>,
+    {
+        (**self).try_write_iter(words)
+    }
+}
+
+impl<T, W> blocking::spi::Transactional<W> for Box<T>
+where
+    T: blocking::spi::Transactional<W> + ?Sized,
+    W: 'static,
+{
+    type Error = T::Error;
+
+    fn try_exec<'a>(
+        &mut self,
+        operations: &mut [blocking::spi::Operation<'a, W>],
+    ) -> Result<(), Self::Error> {
+        (**self).try_exec(operations)
+    }
+}
+
+impl<T, A> i2c::Read<A> for Box<T>
+where
+    T: i2c::Read<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).try_read(address, buffer)
+    }
+}
+
+impl<T, A> i2c::Write<A> for Box<T>
+where
+    T: i2c::Write<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).try_write(address, bytes)
+    }
+}
+
+impl<T, A> i2c::WriteIter<A> for Box<T>
+where
+    T: i2c::WriteIter<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        (**self).try_write_iter(address, bytes)
+    }
+}
+
+impl<T, A> i2c::WriteRead<A> for Box<T>
+where
+    T: i2c::WriteRead<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        (**self).try_write_read(address, bytes, buffer)
+    }
+}
+
+impl<T, A> i2c::WriteIterRead<A> for Box<T>
+where
+    T: i2c::WriteIterRead<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        (**self).try_write_iter_read(address, bytes, buffer)
+    }
+}
+
+impl<T, A> i2c::Transactional<A> for Box<T>
+where
+    T: i2c::Transactional<A> + ?Sized,
+    A: i2c::AddressMode,
+{
+    type Error = T::Error;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        (**self).try_exec(address, operations)
+    }
+}
+
+impl<T, UXX> delay::DelayMs<UXX> for Box<T>
+where
+    T: delay::DelayMs<UXX> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_delay_ms(&mut self, ms: UXX) -> Result<(), Self::Error> {
+        (**self).try_delay_ms(ms)
+    }
+}
+
+impl<T, UXX> delay::DelayUs<UXX> for Box<T>
+where
+    T: delay::DelayUs<UXX> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_delay_us(&mut self, us: UXX) -> Result<(), Self::Error> {
+        (**self).try_delay_us(us)
+    }
+}
+
+impl<T> delay::DelayNs for Box<T>
+where
+    T: delay::DelayNs + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        (**self).try_delay_ns(ns)
+    }
+
+    fn try_delay_micros(&mut self, us: u32) -> Result<(), Self::Error> {
+        (**self).try_delay_micros(us)
+    }
+
+    fn try_delay_millis(&mut self, ms: u32) -> Result<(), Self::Error> {
+        (**self).try_delay_millis(ms)
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> CountDown for Box<T>
+where
+    T: CountDown + ?Sized,
+{
+    type Error = T::Error;
+    type Time = T::Time;
+
+    fn try_start<C>(&mut self, count: C) -> Result<(), Self::Error>
+    where
+        C: Into<Self::Time>,
+    {
+        (**self).try_start(count)
+    }
+
+    fn try_wait(&mut self) -> nb::Result<(), Self::Error> {
+        (**self).try_wait()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Periodic for Box<T>
+where
+    T: timer::Periodic + ?Sized,
+{
+    fn try_get_period(&self) -> Result<Self::Time, Self::Error> {
+        (**self).try_get_period()
+    }
+
+    fn try_set_period<P>(&mut self, period: P) -> Result<(), Self::Error>
+    where
+        P: Into<Self::Time>,
+    {
+        (**self).try_set_period(period)
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Cancel for Box<T>
+where
+    T: timer::Cancel + ?Sized,
+{
+    fn try_cancel(&mut self) -> Result<(), Self::Error> {
+        (**self).try_cancel()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Pause for Box<T>
+where
+    T: timer::Pause + ?Sized,
+{
+    fn try_pause(&mut self) -> Result<(), Self::Error> {
+        (**self).try_pause()
+    }
+
+    fn try_resume(&mut self) -> Result<(), Self::Error> {
+        (**self).try_resume()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Remaining for Box<T>
+where
+    T: timer::Remaining + ?Sized,
+{
+    fn try_remaining(&self) -> Result<Self::Time, Self::Error> {
+        (**self).try_remaining()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Elapsed for Box<T>
+where
+    T: timer::Elapsed + ?Sized,
+{
+    fn try_elapsed(&self) -> Result<Self::Time, Self::Error> {
+        (**self).try_elapsed()
+    }
+}
+
+impl<T> timer::Clock for Box<T>
+where
+    T: timer::Clock + ?Sized,
+{
+    type Error = T::Error;
+    type Time = T::Time;
+    type Instant = T::Instant;
+
+    fn try_now(&self) -> Result<Self::Instant, Self::Error> {
+        (**self).try_now()
+    }
+}
+
+#[cfg(feature = "nb")]
+impl<T> timer::Alarm for Box<T>
+where
+    T: timer::Alarm + ?Sized,
+{
+    fn try_set_alarm(&mut self, instant: Self::Instant) -> Result<(), Self::Error> {
+        (**self).try_set_alarm(instant)
+    }
+
+    fn try_wait_alarm(&mut self) -> nb::Result<(), Self::Error> {
+        (**self).try_wait_alarm()
+    }
+
+    fn try_clear_alarm(&mut self) -> Result<(), Self::Error> {
+        (**self).try_clear_alarm()
+    }
+}
+
+#[cfg(feature = "adc")]
+impl<T, ADC, Word, Pin> OneShot<ADC, Word, Pin> for Box<T>
+where
+    T: OneShot<ADC, Word, Pin> + ?Sized,
+    Pin: adc::Channel<ADC>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, pin: &mut Pin) -> nb::Result<Word, Self::Error> {
+        (**self).try_read(pin)
+    }
+}
+
+#[cfg(feature = "rng")]
+impl<T> rng::Read for Box<T>
+where
+    T: rng::Read + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error> {
+        (**self).try_read(buf)
+    }
+}
+
+#[cfg(feature = "rng")]
+impl<T> rng::CryptoRng for Box<T> where T: rng::CryptoRng + ?Sized {}
+
+#[cfg(feature = "rng")]
+impl<T> blocking::rng::Read for Box<T>
+where
+    T: blocking::rng::Read + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).try_read(buffer)
+    }
+}
+
+#[cfg(feature = "pwm")]
+impl<T> Pwm for Box<T>
+where
+    T: Pwm + ?Sized,
+{
+    type Error = T::Error;
+    type Channel = T::Channel;
+    type Time = T::Time;
+    type Duty = T::Duty;
+
+    fn try_disable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error> {
+        (**self).try_disable(channel)
+    }
+
+    fn try_enable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error> {
+        (**self).try_enable(channel)
+    }
+
+    fn try_get_period(&self) -> Result<Self::Time, Self::Error> {
+        (**self).try_get_period()
+    }
+
+    fn try_get_duty(&self, channel: &Self::Channel) -> Result<Self::Duty, Self::Error> {
+        (**self).try_get_duty(channel)
+    }
+
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error> {
+        (**self).try_get_max_duty()
+    }
+
+    fn try_set_duty(
+        &mut self,
+        channel: &Self::Channel,
+        duty: Self::Duty,
+    ) -> Result<(), Self::Error> {
+        (**self).try_set_duty(channel, duty)
+    }
+
+    fn try_set_period<P>(&mut self, period: P) -> Result<(), Self::Error>
+    where
+        P: Into<Self::Time>,
+    {
+        (**self).try_set_period(period)
+    }
+
+    fn try_set_duty_fraction(
+        &mut self,
+        channel: &Self::Channel,
+        num: u32,
+        denom: u32,
+    ) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        (**self).try_set_duty_fraction(channel, num, denom)
+    }
+
+    fn try_set_duty_percent(
+        &mut self,
+        channel: &Self::Channel,
+        percent: u8,
+    ) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        (**self).try_set_duty_percent(channel, percent)
+    }
+}
+
+#[cfg(feature = "pwm")]
+impl<T> PwmPin for Box<T>
+where
+    T: PwmPin + ?Sized,
+{
+    type Error = T::Error;
+    type Duty = T::Duty;
+
+    fn try_disable(&mut self) -> Result<(), Self::Error> {
+        (**self).try_disable()
+    }
+
+    fn try_enable(&mut self) -> Result<(), Self::Error> {
+        (**self).try_enable()
+    }
+
+    fn try_get_duty(&self) -> Result<Self::Duty, Self::Error> {
+        (**self).try_get_duty()
+    }
+
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error> {
+        (**self).try_get_max_duty()
+    }
+
+    fn try_set_duty(&mut self, duty: Self::Duty) -> Result<(), Self::Error> {
+        (**self).try_set_duty(duty)
+    }
+}
+
+#[cfg(feature = "qei")]
+impl<T> Qei for Box<T>
+where
+    T: Qei + ?Sized,
+{
+    type Error = T::Error;
+    type Count = T::Count;
+
+    fn try_count(&self) -> Result<Self::Count, Self::Error> {
+        (**self).try_count()
+    }
+
+    fn try_direction(&self) -> Result<Direction, Self::Error> {
+        (**self).try_direction()
+    }
+}
+
+#[cfg(feature = "watchdog")]
+impl<T> Watchdog for Box<T>
+where
+    T: Watchdog + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_feed(&mut self) -> Result<(), Self::Error> {
+        (**self).try_feed()
+    }
+}
+
+#[cfg(feature = "capture")]
+impl<T> Capture for Box<T>
+where
+    T: Capture + ?Sized,
+{
+    type Error = T::Error;
+    type Channel = T::Channel;
+    type Time = T::Time;
+    type Capture = T::Capture;
+
+    fn try_capture(&mut self, channel: &Self::Channel) -> nb::Result<Self::Capture, Self::Error> {
+        (**self).try_capture(channel)
+    }
+
+    fn try_disable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error> {
+        (**self).try_disable(channel)
+    }
+
+    fn try_enable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error> {
+        (**self).try_enable(channel)
+    }
+
+    fn try_get_resolution(&self) -> Result<Self::Time, Self::Error> {
+        (**self).try_get_resolution()
+    }
+
+    fn try_set_resolution<R>(&mut self, resolution: R) -> Result<(), Self::Error>
+    where
+        R: Into<Self::Time>,
+    {
+        (**self).try_set_resolution(resolution)
+    }
+}
+
+#[cfg(feature = "can")]
+impl<T> can::Configure for Box<T>
+where
+    T: can::Configure + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_set_mode(&mut self, mode: can::Mode) -> Result<(), Self::Error> {
+        (**self).try_set_mode(mode)
+    }
+
+    fn try_set_automatic_retransmission(&mut self, enabled: bool) -> Result<(), Self::Error> {
+        (**self).try_set_automatic_retransmission(enabled)
+    }
+
+    fn try_set_bit_timing(&mut self, timing: can::BitTiming) -> Result<(), Self::Error> {
+        (**self).try_set_bit_timing(timing)
+    }
+}
+
+#[cfg(feature = "can")]
+impl<T> can::Mailboxes for Box<T>
+where
+    T: can::Mailboxes + ?Sized,
+{
+    type Error = T::Error;
+    type Frame = T::Frame;
+
+    fn mailboxes(&self) -> u8 {
+        (**self).mailboxes()
+    }
+
+    fn try_set_transmit_order(&mut self, order: can::TransmitOrder) -> Result<(), Self::Error> {
+        (**self).try_set_transmit_order(order)
+    }
+
+    fn try_transmit(&mut self, mailbox: u8, frame: &Self::Frame) -> nb::Result<(), Self::Error> {
+        (**self).try_transmit(mailbox, frame)
+    }
+
+    fn try_request_remote(
+        &mut self,
+        mailbox: u8,
+        id: can::Id,
+        dlc: u8,
+    ) -> nb::Result<(), Self::Error> {
+        (**self).try_request_remote(mailbox, id, dlc)
+    }
+
+    fn try_is_pending(&self, mailbox: u8) -> Result<bool, Self::Error> {
+        (**self).try_is_pending(mailbox)
+    }
+
+    fn try_abort(&mut self, mailbox: u8) -> Result<bool, Self::Error> {
+        (**self).try_abort(mailbox)
+    }
+}
+
+#[cfg(feature = "rtc")]
+impl<T> rtc::Calibrate for Box<T>
+where
+    T: rtc::Calibrate + ?Sized,
+{
+    type Error = T::Error;
+
+    fn resolution(&self) -> u32 {
+        (**self).resolution()
+    }
+
+    fn try_correction(&self) -> Result<i32, Self::Error> {
+        (**self).try_correction()
+    }
+
+    fn try_set_correction(&mut self, ppb: i32) -> Result<i32, Self::Error> {
+        (**self).try_set_correction(ppb)
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<T> nor_flash::ReadNorFlash for Box<T>
+where
+    T: nor_flash::ReadNorFlash + ?Sized,
+{
+    type Error = T::Error;
+
+    const READ_SIZE: usize = T::READ_SIZE;
+
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).try_read(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<T> nor_flash::NorFlash for Box<T>
+where
+    T: nor_flash::NorFlash + ?Sized,
+{
+    const WRITE_SIZE: usize = T::WRITE_SIZE;
+    const ERASE_SIZE: usize = T::ERASE_SIZE;
+
+    fn try_erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        (**self).try_erase(from, to)
+    }
+
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).try_write(offset, bytes)
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<T> Otp for Box<T>
+where
+    T: Otp + ?Sized,
+{
+    type Error = T::Error;
+    type Word = T::Word;
+
+    const BLANK: Self::Word = T::BLANK;
+
+    fn word_count(&self) -> usize {
+        (**self).word_count()
+    }
+
+    fn try_read(&mut self, index: usize) -> Result<Self::Word, Self::Error> {
+        (**self).try_read(index)
+    }
+
+    fn try_program_irreversible(
+        &mut self,
+        index: usize,
+        word: Self::Word,
+    ) -> Result<(), Self::Error> {
+        (**self).try_program_irreversible(index, word)
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<T> Eeprom for Box<T>
+where
+    T: Eeprom + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).try_read(offset, bytes)
+    }
+
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).try_write(offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+}
+
+#[cfg(feature = "power")]
+impl<T> power::WakeupSource for Box<T>
+where
+    T: power::WakeupSource + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_enable_wakeup(&mut self) -> Result<(), Self::Error> {
+        (**self).try_enable_wakeup()
+    }
+
+    fn try_disable_wakeup(&mut self) -> Result<(), Self::Error> {
+        (**self).try_disable_wakeup()
+    }
+
+    fn try_is_wakeup_enabled(&self) -> Result<bool, Self::Error> {
+        (**self).try_is_wakeup_enabled()
+    }
+
+    fn try_caused_wakeup(&self) -> Result<bool, Self::Error> {
+        (**self).try_caused_wakeup()
+    }
+}
+
+#[cfg(feature = "power")]
+impl<T> power::BrownOut for Box<T>
+where
+    T: power::BrownOut + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_threshold(&self) -> Result<u16, Self::Error> {
+        (**self).try_threshold()
+    }
+
+    fn try_set_threshold(&mut self, millivolts: u16) -> Result<u16, Self::Error> {
+        (**self).try_set_threshold(millivolts)
+    }
+
+    fn try_brown_out_occurred(&mut self) -> Result<bool, Self::Error> {
+        (**self).try_brown_out_occurred()
+    }
+}
+
+#[cfg(feature = "power")]
+impl<T> power::FrequencyScaling for Box<T>
+where
+    T: power::FrequencyScaling + ?Sized,
+{
+    type Error = T::Error;
+    type Level = T::Level;
+
+    fn try_level(&self) -> Result<Self::Level, Self::Error> {
+        (**self).try_level()
+    }
+
+    fn try_frequency(&self) -> Result<Hertz, Self::Error> {
+        (**self).try_frequency()
+    }
+
+    fn try_set_level(
+        &mut self,
+        level: Self::Level,
+        listeners: &mut [&mut dyn FrequencyListener],
+    ) -> Result<(), Self::Error> {
+        (**self).try_set_level(level, listeners)
+    }
+}
+
+#[cfg(feature = "power")]
+impl<T> FrequencyListener for Box<T>
+where
+    T: FrequencyListener + ?Sized,
+{
+    fn frequency_changing(&mut self, from: Hertz, to: Hertz) {
+        (**self).frequency_changing(from, to)
+    }
+
+    fn frequency_changed(&mut self, core: Hertz) {
+        (**self).frequency_changed(core)
+    }
+}
+
+#[cfg(feature = "net")]
+impl<T> net::TcpClient for Box<T>
+where
+    T: net::TcpClient + ?Sized,
+{
+    type Socket = T::Socket;
+    type Error = T::Error;
+
+    fn try_socket(&mut self) -> Result<Self::Socket, Self::Error> {
+        (**self).try_socket()
+    }
+
+    fn try_connect(
+        &mut self,
+        socket: &mut Self::Socket,
+        remote: SocketAddr,
+    ) -> nb::Result<(), Self::Error> {
+        (**self).try_connect(socket, remote)
+    }
+
+    fn try_is_connected(&self, socket: &Self::Socket) -> Result<bool, Self::Error> {
+        (**self).try_is_connected(socket)
+    }
+
+    fn try_send(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &[u8],
+    ) -> nb::Result<usize, Self::Error> {
+        (**self).try_send(socket, buffer)
+    }
+
+    fn try_receive(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &mut [u8],
+    ) -> nb::Result<usize, Self::Error> {
+        (**self).try_receive(socket, buffer)
+    }
+
+    fn try_close(&mut self, socket: Self::Socket) -> Result<(), Self::Error> {
+        (**self).try_close(socket)
+    }
+}
+
+#[cfg(feature = "net")]
+impl<T> net::UdpClient for Box<T>
+where
+    T: net::UdpClient + ?Sized,
+{
+    type Socket = T::Socket;
+    type Error = T::Error;
+
+    fn try_socket(&mut self) -> Result<Self::Socket, Self::Error> {
+        (**self).try_socket()
+    }
+
+    fn try_connect(
+        &mut self,
+        socket: &mut Self::Socket,
+        remote: SocketAddr,
+    ) -> Result<(), Self::Error> {
+        (**self).try_connect(socket, remote)
+    }
+
+    fn try_send(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &[u8],
+    ) -> nb::Result<(), Self::Error> {
+        (**self).try_send(socket, buffer)
+    }
+
+    fn try_receive(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &mut [u8],
+    ) -> nb::Result<(usize, SocketAddr), Self::Error> {
+        (**self).try_receive(socket, buffer)
+    }
+
+    fn try_close(&mut self, socket: Self::Socket) -> Result<(), Self::Error> {
+        (**self).try_close(socket)
+    }
+}
+
+#[cfg(feature = "net")]
+impl<T> net::Dns for Box<T>
+where
+    T: net::Dns + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_resolve(
+        &mut self,
+        hostname: &str,
+        family: AddressFamily,
+    ) -> nb::Result<IpAddr, Self::Error> {
+        (**self).try_resolve(hostname, family)
+    }
+
+    fn try_reverse_lookup<'b>(
+        &mut self,
+        ip: IpAddr,
+        buffer: &'b mut [u8],
+    ) -> nb::Result<&'b str, Self::Error> {
+        (**self).try_reverse_lookup(ip, buffer)
+    }
+}
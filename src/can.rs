@@ -0,0 +1,247 @@
+//! Controller Area Network
+//!
+//! - [`Configure`] changes the operating mode, the automatic retransmission and the bit timing
+//!   of a CAN controller at runtime, as needed by bus analyzers, diagnostic tools and conformance
+//!   testers.
+//! - [`Mailboxes`] gives schedulers control over the transmit mailboxes: which frame goes in
+//!   which mailbox, in which order they are sent, remote frames and aborts.
+//!
+//! [`Configure`]: trait.Configure.html
+//! [`Mailboxes`]: trait.Mailboxes.html
+
+use crate::time::{Hertz, Rate};
+
+/// Operating mode of a CAN controller
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Takes part in bus traffic
+    Normal,
+    /// Receives frames without ever driving the bus, not even to acknowledge
+    ///
+    /// Also known as listen-only or bus monitoring mode.
+    Silent,
+    /// Receives its own frames, which are also sent on the bus
+    ///
+    /// Frames are acknowledged internally, so they are received even if no other node is
+    /// connected.
+    Loopback,
+    /// Receives its own frames, without driving the bus
+    ///
+    /// For self tests that must not disturb the bus.
+    SilentLoopback,
+}
+
+/// Bit timing, in time quanta
+///
+/// A bit lasts `1 + seg1 + seg2` time quanta of `prescaler` controller clock cycles each; the
+/// sample point is at the end of `seg1`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BitTiming {
+    /// Controller clock cycles per time quantum
+    pub prescaler: u16,
+    /// Time quanta before the sample point, propagation segment included
+    pub seg1: u8,
+    /// Time quanta after the sample point
+    pub seg2: u8,
+    /// Maximum number of time quanta a resynchronization may add or remove
+    pub sjw: u8,
+}
+
+impl BitTiming {
+    /// Returns the number of time quanta of a bit
+    pub fn quanta(&self) -> u32 {
+        1 + u32::from(self.seg1) + u32::from(self.seg2)
+    }
+
+    /// Returns the bitrate for a controller clock of `clock`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_hal::can::BitTiming;
+    /// use embedded_hal::time::U32Ext;
+    ///
+    /// // sample point at 87.5 %
+    /// let timing = BitTiming { prescaler: 6, seg1: 13, seg2: 2, sjw: 1 };
+    ///
+    /// assert_eq!(timing.bitrate(48.mhz()), 500_000.hz());
+    /// ```
+    pub fn bitrate<F>(&self, clock: F) -> Hertz
+    where
+        F: Into<Rate>,
+    {
+        Hertz::from(clock.into()) / (u32::from(self.prescaler) * self.quanta())
+    }
+}
+
+/// Runtime configuration of a CAN controller
+///
+/// # Examples
+///
+/// Monitoring a bus without disturbing it
+///
+/// ```
+/// use embedded_hal::can::{Configure, Mode};
+///
+/// fn monitor<C: Configure>(can: &mut C) -> Result<(), C::Error> {
+///     can.try_set_mode(Mode::Silent)?;
+///     // a frame that is never acknowledged would otherwise be sent forever
+///     can.try_set_automatic_retransmission(false)
+/// }
+///
+/// let mut can: Can1 = {
+///     // ..
+/// #   Can1
+/// };
+///
+/// monitor(&mut can).unwrap();
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::can::BitTiming;
+/// # struct Can1;
+/// # impl Configure for Can1 {
+/// #     type Error = Infallible;
+/// #     fn try_set_mode(&mut self, _: Mode) -> Result<(), Infallible> { Ok(()) }
+/// #     fn try_set_automatic_retransmission(&mut self, _: bool) -> Result<(), Infallible> { Ok(()) }
+/// #     fn try_set_bit_timing(&mut self, _: BitTiming) -> Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+///
+/// # Contract
+///
+/// - The controller MAY leave the bus while it is reconfigured; frames waiting to be sent MAY be
+///   dropped.
+pub trait Configure {
+    /// An enumeration of configuration errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *unsupported*, the controller doesn't support the mode or the bit timing
+    /// - *timeout*, the controller didn't enter or leave its configuration mode
+    type Error;
+
+    /// Sets the operating mode
+    fn try_set_mode(&mut self, mode: Mode) -> Result<(), Self::Error>;
+
+    /// Enables or disables the automatic retransmission of frames that were not acknowledged or
+    /// lost arbitration
+    ///
+    /// Automatic retransmission is enabled by default, as required by the CAN specification.
+    fn try_set_automatic_retransmission(&mut self, enabled: bool) -> Result<(), Self::Error>;
+
+    /// Sets the bit timing
+    fn try_set_bit_timing(&mut self, timing: BitTiming) -> Result<(), Self::Error>;
+}
+
+/// CAN identifier
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Id {
+    /// 11-bit standard identifier
+    Standard(u16),
+    /// 29-bit extended identifier
+    Extended(u32),
+}
+
+/// Order in which pending mailboxes are sent
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransmitOrder {
+    /// Frame with the highest priority identifier first, as arbitration on the bus would
+    Identifier,
+    /// Mailboxes in the order they were filled
+    Request,
+}
+
+/// Transmit mailboxes of a CAN controller
+///
+/// The frame type is defined by the implementation.
+///
+/// # Examples
+///
+/// Replacing a stale frame before its deadline
+///
+/// ```
+/// #[macro_use(block)]
+/// extern crate nb;
+/// # extern crate embedded_hal;
+///
+/// use embedded_hal::can::{Mailboxes, TransmitOrder};
+///
+/// // mailbox reserved for the periodic status frame
+/// const STATUS: u8 = 0;
+///
+/// fn send_status<M: Mailboxes>(can: &mut M, status: &M::Frame) -> Result<(), M::Error> {
+///     if can.try_is_pending(STATUS)? {
+///         // the previous status lost arbitration until now, it is outdated
+///         can.try_abort(STATUS)?;
+///     }
+///
+///     block!(can.try_transmit(STATUS, status))
+/// }
+///
+/// # fn main() {
+/// let mut can: Can1 = {
+///     // ..
+/// #   Can1
+/// };
+///
+/// can.try_set_transmit_order(TransmitOrder::Identifier).unwrap();
+/// send_status(&mut can, &[0x01]).unwrap();
+/// # }
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::can::Id;
+/// # struct Can1;
+/// # impl Mailboxes for Can1 {
+/// #     type Error = Infallible;
+/// #     type Frame = [u8; 1];
+/// #     fn mailboxes(&self) -> u8 { 3 }
+/// #     fn try_set_transmit_order(&mut self, _: TransmitOrder) -> Result<(), Infallible> { Ok(()) }
+/// #     fn try_transmit(&mut self, _: u8, _: &[u8; 1]) -> nb::Result<(), Infallible> { Ok(()) }
+/// #     fn try_request_remote(&mut self, _: u8, _: Id, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// #     fn try_is_pending(&self, _: u8) -> Result<bool, Infallible> { Ok(true) }
+/// #     fn try_abort(&mut self, _: u8) -> Result<bool, Infallible> { Ok(true) }
+/// # }
+/// ```
+///
+/// # Contract
+///
+/// - Methods taking a `mailbox` MUST panic if it is not below `mailboxes()`.
+pub trait Mailboxes {
+    /// An enumeration of transmission errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *not acknowledged*, with automatic retransmission disabled
+    /// - *arbitration lost*, with automatic retransmission disabled
+    /// - *bus off*, the controller left the bus after too many errors
+    type Error;
+
+    /// Data or remote frame
+    type Frame;
+
+    /// Returns the number of transmit mailboxes
+    fn mailboxes(&self) -> u8;
+
+    /// Sets the order in which pending mailboxes are sent
+    fn try_set_transmit_order(&mut self, order: TransmitOrder) -> Result<(), Self::Error>;
+
+    /// Puts `frame` in `mailbox` and requests its transmission
+    ///
+    /// Returns `WouldBlock` while `mailbox` is still pending.
+    fn try_transmit(&mut self, mailbox: u8, frame: &Self::Frame) -> nb::Result<(), Self::Error>;
+
+    /// Requests, from `mailbox`, a remote frame of `dlc` bytes with identifier `id`
+    ///
+    /// The node producing `id` answers with a data frame, received like any other frame.
+    /// Returns `WouldBlock` while `mailbox` is still pending.
+    fn try_request_remote(&mut self, mailbox: u8, id: Id, dlc: u8) -> nb::Result<(), Self::Error>;
+
+    /// Returns `true` if `mailbox` holds a frame that wasn't sent yet
+    fn try_is_pending(&self, mailbox: u8) -> Result<bool, Self::Error>;
+
+    /// Aborts the transmission of `mailbox`
+    ///
+    /// Returns `false` if the frame was sent before the abort took effect, or if the mailbox was
+    /// empty.
+    fn try_abort(&mut self, mailbox: u8) -> Result<bool, Self::Error>;
+}
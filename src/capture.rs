@@ -2,6 +2,8 @@
 
 use nb;
 
+use crate::time::{Duration, Rate};
+
 /// Input capture
 ///
 /// # Examples
@@ -22,10 +24,10 @@ use nb;
 /// #       Capture1
 ///     };
 ///
-///     capture.try_set_resolution(1.ms()).unwrap();
+///     capture.try_set_resolution(1.millis()).unwrap();
 ///
-///     let before = block!(capture.try_capture(Channel::_1)).unwrap();
-///     let after = block!(capture.try_capture(Channel::_1)).unwrap();
+///     let before = block!(capture.try_capture(&Channel::_1)).unwrap();
+///     let after = block!(capture.try_capture(&Channel::_1)).unwrap();
 ///
 ///     let period = after.wrapping_sub(before);
 ///
@@ -33,21 +35,19 @@ use nb;
 /// }
 ///
 /// # use core::convert::Infallible;
-/// # struct MilliSeconds(u32);
-/// # trait U32Ext { fn ms(self) -> MilliSeconds; }
-/// # impl U32Ext for u32 { fn ms(self) -> MilliSeconds { MilliSeconds(self) } }
+/// # use hal::time::Duration;
 /// # struct Capture1;
 /// # enum Channel { _1 }
 /// # impl hal::capture::Capture for Capture1 {
 /// #     type Error = Infallible;
 /// #     type Capture = u16;
 /// #     type Channel = Channel;
-/// #     type Time = MilliSeconds;
-/// #     fn try_capture(&mut self, _: Channel) -> ::nb::Result<u16, Self::Error> { Ok(0) }
-/// #     fn try_disable(&mut self, _: Channel) -> Result<(), Self::Error> { unimplemented!() }
-/// #     fn try_enable(&mut self, _: Channel) -> Result<(), Self::Error> { unimplemented!() }
-/// #     fn try_get_resolution(&self) -> Result<MilliSeconds, Self::Error> { unimplemented!() }
-/// #     fn try_set_resolution<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<MilliSeconds> { Ok(()) }
+/// #     type Time = Duration;
+/// #     fn try_capture(&mut self, _: &Channel) -> ::nb::Result<u16, Self::Error> { Ok(0) }
+/// #     fn try_disable(&mut self, _: &Channel) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn try_enable(&mut self, _: &Channel) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn try_get_resolution(&self) -> Result<Duration, Self::Error> { unimplemented!() }
+/// #     fn try_set_resolution<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Duration> { Ok(()) }
 /// # }
 /// ```
 // unproven reason: pre-singletons API. With singletons a `CapturePin` (cf. `PwmPin`) trait seems more
@@ -65,6 +65,9 @@ pub trait Capture {
     ///
     /// If your `Capture` interface has no channels you can use the type `()`
     /// here
+    ///
+    /// Channels are passed by reference, so the channel type doesn't need to be `Copy` and can
+    /// own resources, e.g. the pin routed to the channel.
     type Channel;
 
     /// A time unit that can be converted into a human time unit (e.g. seconds)
@@ -78,13 +81,13 @@ pub trait Capture {
     ///
     /// NOTE that you must multiply the returned value by the *resolution* of
     /// this `Capture` interface to get a human time unit (e.g. seconds)
-    fn try_capture(&mut self, channel: Self::Channel) -> nb::Result<Self::Capture, Self::Error>;
+    fn try_capture(&mut self, channel: &Self::Channel) -> nb::Result<Self::Capture, Self::Error>;
 
     /// Disables a capture `channel`
-    fn try_disable(&mut self, channel: Self::Channel) -> Result<(), Self::Error>;
+    fn try_disable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
 
     /// Enables a capture `channel`
-    fn try_enable(&mut self, channel: Self::Channel) -> Result<(), Self::Error>;
+    fn try_enable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
 
     /// Returns the current resolution
     fn try_get_resolution(&self) -> Result<Self::Time, Self::Error>;
@@ -94,3 +97,255 @@ pub trait Capture {
     where
         R: Into<Self::Time>;
 }
+
+/// Edge of the input signal that triggers a capture
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Edge {
+    /// Rising edge
+    Rising,
+    /// Falling edge
+    Falling,
+    /// Both edges
+    Both,
+}
+
+/// Input capture whose active edge can be selected per channel
+///
+/// # Examples
+///
+/// Measure the width of a high pulse
+///
+/// ```
+/// use embedded_hal::capture::{Edge, SetEdge};
+///
+/// fn pulse_width<C>(capture: &mut C, channel: &C::Channel) -> nb::Result<u16, C::Error>
+/// where
+///     C: SetEdge<Capture = u16>,
+/// {
+///     capture.try_set_edge(channel, Edge::Both)?;
+///
+///     let start = nb::block!(capture.try_capture(channel))?;
+///     let end = nb::block!(capture.try_capture(channel))?;
+///     Ok(end.wrapping_sub(start))
+/// }
+/// ```
+pub trait SetEdge: Capture {
+    /// Sets the edge(s) of the input signal that trigger a capture on `channel`
+    fn try_set_edge(&mut self, channel: &Self::Channel, edge: Edge) -> Result<(), Self::Error>;
+}
+
+/// Input capture that can report lost captures
+///
+/// An overcapture happens when a new capture overwrites a value that was not read yet. Periods
+/// computed across an overcapture are wrong; checking the flag lets measurement code discard
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::capture::Overcapture;
+///
+/// fn period<C>(capture: &mut C, channel: &C::Channel) -> nb::Result<Option<u16>, C::Error>
+/// where
+///     C: Overcapture<Capture = u16>,
+/// {
+///     capture.try_clear_overcapture(channel)?;
+///
+///     let before = nb::block!(capture.try_capture(channel))?;
+///     let after = nb::block!(capture.try_capture(channel))?;
+///
+///     if capture.try_is_overcaptured(channel)? {
+///         // an edge was missed, the period is wrong
+///         return Ok(None);
+///     }
+///
+///     Ok(Some(after.wrapping_sub(before)))
+/// }
+/// ```
+pub trait Overcapture: Capture {
+    /// Returns `true` if a capture of `channel` was overwritten before it was read since the
+    /// flag was last cleared
+    fn try_is_overcaptured(&self, channel: &Self::Channel) -> Result<bool, Self::Error>;
+
+    /// Clears the overcapture flag of `channel`
+    fn try_clear_overcapture(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
+}
+
+/// Frequency measurement of an input signal
+///
+/// The implementation counts the edges of the input signal over a *gate time* (or measures its
+/// period, for low frequencies) and converts the result to hertz.
+///
+/// # Examples
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::prelude::*;
+/// use hal::preludes::capture::*;
+///
+/// fn main() {
+///     let mut meter: Tim2 = {
+///         // ..
+/// #       Tim2
+///     };
+///
+///     // 100 ms gate: 10 Hz resolution
+///     meter.try_set_gate_time(100.millis()).unwrap();
+///
+///     let frequency = block!(meter.try_measure()).unwrap();
+///
+///     println!("Frequency: {} Hz", frequency.as_hz());
+/// }
+///
+/// # use core::convert::Infallible;
+/// # use hal::time::{Duration, Rate};
+/// # struct Tim2;
+/// # impl hal::capture::FrequencyMeter for Tim2 {
+/// #     type Error = Infallible;
+/// #     fn try_measure(&mut self) -> ::nb::Result<Rate, Self::Error> { Ok(Rate::from_hz(0)) }
+/// #     fn try_get_gate_time(&self) -> Result<Duration, Self::Error> { unimplemented!() }
+/// #     fn try_set_gate_time<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Duration> { Ok(()) }
+/// # }
+/// ```
+pub trait FrequencyMeter {
+    /// Enumeration of `FrequencyMeter` errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *overflow*, the input frequency is too high to be counted over the gate time
+    type Error;
+
+    /// "Waits" for the end of a measurement and returns the measured frequency
+    ///
+    /// The first call starts a measurement over one gate time; `WouldBlock` is returned until the
+    /// measurement is over.
+    fn try_measure(&mut self) -> nb::Result<Rate, Self::Error>;
+
+    /// Returns the current gate time
+    fn try_get_gate_time(&self) -> Result<Duration, Self::Error>;
+
+    /// Sets the gate time of the following measurements
+    ///
+    /// Longer gate times improve the resolution of the measurement.
+    fn try_set_gate_time<T>(&mut self, gate_time: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>;
+}
+
+/// Period and pulse width of a PWM signal
+///
+/// The duty cycle of the signal is `pulse_width / period`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PwmMeasurement {
+    /// Time between two consecutive rising edges
+    pub period: Duration,
+    /// Time the signal stays high during one period
+    pub pulse_width: Duration,
+}
+
+/// PWM input measurement
+///
+/// Typically implemented by a timer capturing both edges of the input signal on two channels.
+///
+/// # Examples
+///
+/// Read the throttle channel of an RC receiver
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::capture::PwmInput;
+///
+/// fn main() {
+///     let mut input: Tim3 = {
+///         // ..
+/// #       Tim3
+///     };
+///
+///     let measurement = block!(input.try_measure(&Channel::_1)).unwrap();
+///
+///     // 1 ms = idle, 2 ms = full throttle
+///     let throttle = measurement.pulse_width.as_micros().saturating_sub(1_000);
+///     println!("Throttle: {} / 1000", throttle);
+/// }
+///
+/// # use core::convert::Infallible;
+/// # use hal::capture::PwmMeasurement;
+/// # use hal::time::Duration;
+/// # struct Tim3;
+/// # enum Channel { _1 }
+/// # impl PwmInput for Tim3 {
+/// #     type Error = Infallible;
+/// #     type Channel = Channel;
+/// #     fn try_measure(&mut self, _: &Channel) -> ::nb::Result<PwmMeasurement, Self::Error> {
+/// #         Ok(PwmMeasurement { period: Duration::from_millis(20), pulse_width: Duration::from_micros(1_500) })
+/// #     }
+/// # }
+/// ```
+pub trait PwmInput {
+    /// Enumeration of `PwmInput` errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *overcapture*, an edge was missed and the measurement is not reliable
+    type Error;
+
+    /// Enumeration of channels that can be used with this `PwmInput` interface
+    ///
+    /// If your `PwmInput` interface has no channels you can use the type `()`
+    /// here
+    type Channel;
+
+    /// "Waits" for a full period of the signal on `channel` and returns its period and pulse
+    /// width
+    fn try_measure(&mut self, channel: &Self::Channel) -> nb::Result<PwmMeasurement, Self::Error>;
+}
+
+/// DMA-backed input capture
+///
+/// Captures a burst of timestamps without CPU intervention, e.g. to decode an infrared remote
+/// control frame or to sample a signal like a logic analyzer.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::capture::CaptureDma;
+/// use embedded_hal::dma::Transfer;
+///
+/// static mut EDGES: [u16; 68] = [0; 68];
+///
+/// fn receive_ir<C: CaptureDma<u16>>(capture: C, channel: C::Channel) -> C {
+///     let buffer = unsafe { &mut EDGES };
+///     let transfer = capture.try_capture_dma(channel, buffer).ok().unwrap();
+///
+///     let (edges, capture) = transfer.try_wait().ok().unwrap();
+///     // decode the pulse widths: `edges[i + 1].wrapping_sub(edges[i])`
+///     capture
+/// }
+/// ```
+pub trait CaptureDma<Word: 'static>: Sized {
+    /// An enumeration of errors that can occur while starting the transfer
+    type Error;
+
+    /// Enumeration of channels that can be used with this interface
+    type Channel;
+
+    /// Handle to the ongoing transfer
+    ///
+    /// Errors that occur while the transfer is in progress (e.g. an overcapture) are reported by
+    /// this handle.
+    type Transfer: crate::dma::Transfer<Output = (&'static mut [Word], Self)>;
+
+    /// Starts capturing the counter value on every transition of `channel`, until `buffer` is
+    /// full
+    fn try_capture_dma(
+        self,
+        channel: Self::Channel,
+        buffer: &'static mut [Word],
+    ) -> Result<Self::Transfer, Self::Error>;
+}
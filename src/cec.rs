@@ -0,0 +1,139 @@
+//! HDMI Consumer Electronics Control
+//!
+//! CEC is the single wire bus HDMI devices use to control each other, e.g. to turn a TV on or
+//! change its input. Several microcontroller families have a CEC peripheral that handles the bit
+//! timings, arbitration and acknowledgements; [`Cec`] exposes it at the frame level.
+//!
+//! [`Cec`]: trait.Cec.html
+//!
+//! # Examples
+//!
+//! Answering `Give Physical Address` requests
+//!
+//! ```
+//! #[macro_use(block)]
+//! extern crate nb;
+//! # extern crate embedded_hal;
+//!
+//! use embedded_hal::cec::{Cec, Frame, BROADCAST};
+//!
+//! const PLAYBACK_DEVICE_1: u8 = 4;
+//! const GIVE_PHYSICAL_ADDRESS: u8 = 0x83;
+//! const REPORT_PHYSICAL_ADDRESS: u8 = 0x84;
+//!
+//! fn serve<C: Cec>(cec: &mut C) -> Result<(), C::Error> {
+//!     cec.try_set_address(PLAYBACK_DEVICE_1)?;
+//!
+//!     loop {
+//!         let frame = block!(cec.try_receive())?;
+//!
+//!         if frame.data() == [GIVE_PHYSICAL_ADDRESS] {
+//!             // physical address 1.0.0.0, device type playback
+//!             let data = [REPORT_PHYSICAL_ADDRESS, 0x10, 0x00, 0x04];
+//!             let report = Frame::new(PLAYBACK_DEVICE_1, BROADCAST, &data);
+//!             block!(cec.try_transmit(&report))?;
+//! #           return Ok(());
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let mut cec: Cec1 = {
+//!     // ..
+//! #   Cec1
+//! };
+//!
+//! serve(&mut cec).unwrap();
+//! # }
+//!
+//! # struct Cec1;
+//! # impl Cec for Cec1 {
+//! #     type Error = ();
+//! #     fn try_set_address(&mut self, _: u8) -> Result<(), ()> { Ok(()) }
+//! #     fn try_transmit(&mut self, _: &Frame) -> nb::Result<(), ()> { Ok(()) }
+//! #     fn try_receive(&mut self) -> nb::Result<Frame, ()> { Ok(Frame::new(0, 4, &[0x83])) }
+//! # }
+//! ```
+
+/// Logical address of the broadcast destination
+///
+/// As an initiator, it is the address of unregistered devices.
+pub const BROADCAST: u8 = 0xF;
+
+/// CEC frame
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Frame {
+    initiator: u8,
+    destination: u8,
+    data: [u8; 15],
+    len: u8,
+}
+
+impl Frame {
+    /// Creates a frame
+    ///
+    /// `data` is the opcode followed by its operands; an empty frame is a polling message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initiator` or `destination` is not a logical address (`0..=15`) or if `data` is
+    /// longer than 15 bytes.
+    pub fn new(initiator: u8, destination: u8, data: &[u8]) -> Self {
+        assert!(initiator <= 0xF && destination <= 0xF);
+
+        let mut frame = Frame {
+            initiator,
+            destination,
+            data: [0; 15],
+            len: data.len() as u8,
+        };
+        frame.data[..data.len()].copy_from_slice(data);
+        frame
+    }
+
+    /// Returns the logical address of the sender
+    pub fn initiator(&self) -> u8 {
+        self.initiator
+    }
+
+    /// Returns the logical address of the recipient
+    pub fn destination(&self) -> u8 {
+        self.destination
+    }
+
+    /// Returns the opcode and the operands
+    pub fn data(&self) -> &[u8] {
+        &self.data[..usize::from(self.len)]
+    }
+
+    /// Returns `true` if the frame is addressed to every device
+    pub fn is_broadcast(&self) -> bool {
+        self.destination == BROADCAST
+    }
+}
+
+/// HDMI-CEC controller
+pub trait Cec {
+    /// An enumeration of CEC errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *not acknowledged*, no device acknowledged a directly addressed frame
+    /// - *arbitration lost*, another initiator took the bus, after the automatic retries
+    /// - *bit timing*, a received bit was too short or too long
+    type Error;
+
+    /// Sets the logical address of this device
+    ///
+    /// The controller only acknowledges and receives frames sent to this address or broadcast.
+    /// `BROADCAST` unregisters the device.
+    fn try_set_address(&mut self, address: u8) -> Result<(), Self::Error>;
+
+    /// Sends `frame`
+    ///
+    /// Returns `WouldBlock` until the frame has been sent and, unless broadcast, acknowledged.
+    fn try_transmit(&mut self, frame: &Frame) -> nb::Result<(), Self::Error>;
+
+    /// Receives a frame
+    fn try_receive(&mut self) -> nb::Result<Frame, Self::Error>;
+}
@@ -0,0 +1,62 @@
+//! Clock management
+
+use crate::time::Hertz;
+
+/// Clock output (MCO)
+///
+/// Routes an internal clock, divided by a prescaler, to an output pin, e.g. to clock an external
+/// audio codec or Ethernet PHY. The clocks that can be selected are defined by the
+/// implementation; the application picks one and hands the output to the driver.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::clock::ClockOutput;
+/// use embedded_hal::time::{Hertz, U32Ext};
+///
+/// /// Clocks an Ethernet PHY, which needs 25 MHz
+/// fn clock_phy<M: ClockOutput>(mco: &mut M, source: M::Source) -> Result<(), M::Error> {
+///     mco.try_enable(source, 1)?;
+///     assert_eq!(mco.try_frequency()?, 25_000_000.hz());
+///     Ok(())
+/// }
+///
+/// let mut mco: Mco1 = {
+///     // ..
+/// #   Mco1
+/// };
+///
+/// clock_phy(&mut mco, Source::Hse).unwrap();
+///
+/// # use core::convert::Infallible;
+/// # enum Source { Hse }
+/// # struct Mco1;
+/// # impl ClockOutput for Mco1 {
+/// #     type Error = Infallible;
+/// #     type Source = Source;
+/// #     fn try_enable(&mut self, _: Source, _: u16) -> Result<(), Infallible> { Ok(()) }
+/// #     fn try_disable(&mut self) -> Result<(), Infallible> { Ok(()) }
+/// #     fn try_frequency(&self) -> Result<Hertz, Infallible> { Ok(25_000_000.hz()) }
+/// # }
+/// ```
+pub trait ClockOutput {
+    /// An enumeration of clock output errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *unsupported prescaler*, the output can't divide the clock by the prescaler
+    /// - *source disabled*, the selected clock isn't running
+    type Error;
+
+    /// Enumeration of the clocks that can be output
+    type Source;
+
+    /// Outputs `source` divided by `prescaler`
+    fn try_enable(&mut self, source: Self::Source, prescaler: u16) -> Result<(), Self::Error>;
+
+    /// Stops the clock output
+    fn try_disable(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the frequency of the output, zero while disabled
+    fn try_frequency(&self) -> Result<Hertz, Self::Error>;
+}
@@ -0,0 +1,38 @@
+//! Direct Memory Access (DMA) transfers
+//!
+//! Peripheral traits that move data with DMA take ownership of the peripheral and of a `'static`
+//! buffer and return a [`Transfer`] handle. The CPU is free to do other work while the transfer is
+//! in progress; the peripheral and the buffer are handed back once the transfer is over.
+//!
+//! [`Transfer`]: trait.Transfer.html
+
+/// An ongoing DMA transfer
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::dma::Transfer;
+///
+/// fn finish<T: Transfer>(transfer: T) -> Result<T::Output, T::Error> {
+///     while !transfer.try_is_done()? {
+///         // do some other work in the meantime
+///     }
+///
+///     transfer.try_wait()
+/// }
+/// ```
+pub trait Transfer {
+    /// Enumeration of errors that can occur during the transfer
+    type Error;
+
+    /// Resources owned by the transfer, returned once the transfer is over
+    ///
+    /// This is typically a tuple made of the buffer and the peripheral.
+    type Output;
+
+    /// Returns `true` once the transfer is over
+    fn try_is_done(&self) -> Result<bool, Self::Error>;
+
+    /// Blocks until the transfer is over and returns the resources it owned
+    fn try_wait(self) -> Result<Self::Output, Self::Error>;
+}
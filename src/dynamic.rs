@@ -0,0 +1,488 @@
+//! Trait objects
+//!
+//! Most traits of this crate can be used as trait objects, which lets applications with many
+//! drivers avoid monomorphizing each driver for each peripheral. The exceptions are the traits
+//! with generic methods, e.g. the `WriteIter` traits and `CountDown`.
+//!
+//! This module provides:
+//!
+//! - Aliases of the object types of the core traits, e.g. [`DynOutputPin`].
+//! - Object-safe combinations of the bus traits with a single error type, e.g. [`DynI2c`], so a
+//!   single trait object can be handed to a driver needing several of them.
+//! - [`DynCountDown`], an object-safe counterpart of `CountDown`.
+//! - [`MapError`], which converts the errors of an implementation, so peripherals of different
+//!   HALs can be stored behind trait objects with the same error type.
+//!
+//! [`DynOutputPin`]: type.DynOutputPin.html
+//! [`DynI2c`]: trait.DynI2c.html
+//! [`DynCountDown`]: trait.DynCountDown.html
+//! [`MapError`]: struct.MapError.html
+//!
+//! # Examples
+//!
+//! ```
+//! use core::convert::Infallible;
+//! use embedded_hal::digital::OutputPin;
+//! use embedded_hal::dynamic::{DynOutputPin, MapError};
+//! # struct Led;
+//! # impl OutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! # struct ExpanderPin;
+//! # impl OutputPin for ExpanderPin {
+//! #     type Error = ExpanderError;
+//! #     fn try_set_low(&mut self) -> Result<(), ExpanderError> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), ExpanderError> { Ok(()) }
+//! # }
+//! # #[derive(Debug)]
+//! # struct ExpanderError;
+//!
+//! #[derive(Debug)]
+//! enum Error {
+//!     Expander(ExpanderError),
+//! }
+//!
+//! impl From<Infallible> for Error {
+//!     fn from(e: Infallible) -> Self {
+//!         match e {}
+//!     }
+//! }
+//!
+//! impl From<ExpanderError> for Error {
+//!     fn from(e: ExpanderError) -> Self {
+//!         Error::Expander(e)
+//!     }
+//! }
+//!
+//! let mut led = MapError::new(Led);
+//! let mut buzzer = MapError::new(ExpanderPin);
+//! let mut outputs: [&mut DynOutputPin<Error>; 2] = [&mut led, &mut buzzer];
+//!
+//! for output in outputs.iter_mut() {
+//!     output.try_set_high().unwrap();
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::blocking::delay::{DelayMs, DelayNs, DelayUs};
+use crate::blocking::{self, i2c, spi};
+use crate::digital::{InputPin, OutputPin, StatefulOutputPin};
+use crate::serial;
+use crate::spi::FullDuplex;
+use crate::time::Duration;
+use crate::timer::CountDown;
+
+/// Output pin trait object
+pub type DynOutputPin<'a, E> = dyn OutputPin<Error = E> + 'a;
+
+/// Stateful output pin trait object
+pub type DynStatefulOutputPin<'a, E> = dyn StatefulOutputPin<Error = E> + 'a;
+
+/// Input pin trait object
+pub type DynInputPin<'a, E> = dyn InputPin<Error = E> + 'a;
+
+/// Blocking I2C master, object-safe
+///
+/// Implemented for every type implementing `Read`, `Write`, `WriteRead` and `Transactional`
+/// with the same error type.
+pub trait DynI2c<E, A = i2c::SevenBitAddress>:
+    i2c::Read<A, Error = E>
+    + i2c::Write<A, Error = E>
+    + i2c::WriteRead<A, Error = E>
+    + i2c::Transactional<A, Error = E>
+where
+    A: i2c::AddressMode,
+{
+}
+
+impl<T, E, A> DynI2c<E, A> for T
+where
+    A: i2c::AddressMode,
+    T: i2c::Read<A, Error = E>
+        + i2c::Write<A, Error = E>
+        + i2c::WriteRead<A, Error = E>
+        + i2c::Transactional<A, Error = E>,
+{
+}
+
+/// Blocking SPI master, object-safe
+///
+/// Implemented for every type implementing `Transfer`, `Write` and `Transactional` with the same
+/// error type.
+pub trait DynSpi<E, W: 'static = u8>:
+    spi::Transfer<W, Error = E> + spi::Write<W, Error = E> + spi::Transactional<W, Error = E>
+{
+}
+
+impl<T, E, W> DynSpi<E, W> for T
+where
+    W: 'static,
+    T: spi::Transfer<W, Error = E> + spi::Write<W, Error = E> + spi::Transactional<W, Error = E>,
+{
+}
+
+/// Serial interface, object-safe
+///
+/// Implemented for every type implementing `serial::Read` and `serial::Write` with the same error
+/// type.
+pub trait DynSerial<E, Word = u8>:
+    serial::Read<Word, Error = E> + serial::Write<Word, Error = E>
+{
+}
+
+impl<T, E, Word> DynSerial<E, Word> for T where
+    T: serial::Read<Word, Error = E> + serial::Write<Word, Error = E>
+{
+}
+
+/// Count down timer, object-safe
+///
+/// Implemented for every `CountDown` whose `Time` can be created from a `Duration`.
+pub trait DynCountDown {
+    /// An enumeration of `CountDown` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Starts a new count down of `count`
+    fn try_start(&mut self, count: Duration) -> Result<(), Self::Error>;
+
+    /// Non-blockingly "waits" until the count down finishes
+    fn try_wait(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+impl<T> DynCountDown for T
+where
+    T: CountDown,
+    T::Time: From<Duration>,
+{
+    type Error = T::Error;
+
+    fn try_start(&mut self, count: Duration) -> Result<(), Self::Error> {
+        CountDown::try_start(self, count)
+    }
+
+    fn try_wait(&mut self) -> nb::Result<(), Self::Error> {
+        CountDown::try_wait(self)
+    }
+}
+
+/// Implementation wrapper converting errors into `E`
+///
+/// Every error of the wrapped implementation must be convertible into `E`.
+pub struct MapError<T, E> {
+    inner: T,
+    _error: PhantomData<E>,
+}
+
+impl<T, E> MapError<T, E> {
+    /// Wraps `inner`
+    pub fn new(inner: T) -> Self {
+        MapError {
+            inner,
+            _error: PhantomData,
+        }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> T {
+        self.inner
+    }
+}
+
+fn nb_into<T, F, E>(result: nb::Result<T, F>) -> nb::Result<T, E>
+where
+    F: Into<E>,
+{
+    match result {
+        Ok(x) => Ok(x),
+        Err(nb::Error::Other(e)) => Err(nb::Error::Other(e.into())),
+        Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
+    }
+}
+
+impl<T, E> OutputPin for MapError<T, E>
+where
+    T: OutputPin,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_set_low(&mut self) -> Result<(), E> {
+        self.inner.try_set_low().map_err(Into::into)
+    }
+
+    fn try_set_high(&mut self) -> Result<(), E> {
+        self.inner.try_set_high().map_err(Into::into)
+    }
+}
+
+impl<T, E> StatefulOutputPin for MapError<T, E>
+where
+    T: StatefulOutputPin,
+    T::Error: Into<E>,
+{
+    fn try_is_set_high(&self) -> Result<bool, E> {
+        self.inner.try_is_set_high().map_err(Into::into)
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, E> {
+        self.inner.try_is_set_low().map_err(Into::into)
+    }
+}
+
+impl<T, E> InputPin for MapError<T, E>
+where
+    T: InputPin,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_is_high(&self) -> Result<bool, E> {
+        self.inner.try_is_high().map_err(Into::into)
+    }
+
+    fn try_is_low(&self) -> Result<bool, E> {
+        self.inner.try_is_low().map_err(Into::into)
+    }
+}
+
+impl<T, E, A> i2c::Read<A> for MapError<T, E>
+where
+    T: i2c::Read<A>,
+    T::Error: Into<E>,
+    A: i2c::AddressMode,
+{
+    type Error = E;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), E> {
+        self.inner.try_read(address, buffer).map_err(Into::into)
+    }
+}
+
+impl<T, E, A> i2c::Write<A> for MapError<T, E>
+where
+    T: i2c::Write<A>,
+    T::Error: Into<E>,
+    A: i2c::AddressMode,
+{
+    type Error = E;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), E> {
+        self.inner.try_write(address, bytes).map_err(Into::into)
+    }
+}
+
+impl<T, E, A> i2c::WriteRead<A> for MapError<T, E>
+where
+    T: i2c::WriteRead<A>,
+    T::Error: Into<E>,
+    A: i2c::AddressMode,
+{
+    type Error = E;
+
+    fn try_write_read(&mut self, address: A, bytes: &[u8], buffer: &mut [u8]) -> Result<(), E> {
+        self.inner
+            .try_write_read(address, bytes, buffer)
+            .map_err(Into::into)
+    }
+}
+
+impl<T, E, A> i2c::Transactional<A> for MapError<T, E>
+where
+    T: i2c::Transactional<A>,
+    T::Error: Into<E>,
+    A: i2c::AddressMode,
+{
+    type Error = E;
+
+    fn try_exec<'a>(&mut self, address: A, operations: &mut [i2c::Operation<'a>]) -> Result<(), E> {
+        self.inner.try_exec(address, operations).map_err(Into::into)
+    }
+}
+
+impl<T, E, W> FullDuplex<W> for MapError<T, E>
+where
+    T: FullDuplex<W>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_read(&mut self) -> nb::Result<W, E> {
+        nb_into(self.inner.try_read())
+    }
+
+    fn try_send(&mut self, word: W) -> nb::Result<(), E> {
+        nb_into(self.inner.try_send(word))
+    }
+}
+
+impl<T, E, W> spi::Transfer<W> for MapError<T, E>
+where
+    T: spi::Transfer<W>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], E> {
+        self.inner.try_transfer(words).map_err(Into::into)
+    }
+}
+
+impl<T, E, W> spi::Write<W> for MapError<T, E>
+where
+    T: spi::Write<W>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_write(&mut self, words: &[W]) -> Result<(), E> {
+        self.inner.try_write(words).map_err(Into::into)
+    }
+}
+
+impl<T, E, W> spi::WriteIter<W> for MapError<T, E>
+where
+    T: spi::WriteIter<W>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), E>
+    where
+        WI: IntoIterator<Item = W>,
+    {
+        self.inner.try_write_iter(words).map_err(Into::into)
+    }
+}
+
+impl<T, E, W> spi::Transactional<W> for MapError<T, E>
+where
+    T: spi::Transactional<W>,
+    T::Error: Into<E>,
+    W: 'static,
+{
+    type Error = E;
+
+    fn try_exec<'a>(&mut self, operations: &mut [spi::Operation<'a, W>]) -> Result<(), E> {
+        self.inner.try_exec(operations).map_err(Into::into)
+    }
+}
+
+impl<T, E, Word> serial::Read<Word> for MapError<T, E>
+where
+    T: serial::Read<Word>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_read(&mut self) -> nb::Result<Word, E> {
+        nb_into(self.inner.try_read())
+    }
+}
+
+impl<T, E, Word> serial::Write<Word> for MapError<T, E>
+where
+    T: serial::Write<Word>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), E> {
+        nb_into(self.inner.try_write(word))
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), E> {
+        nb_into(self.inner.try_flush())
+    }
+}
+
+impl<T, E, Word> blocking::serial::Write<Word> for MapError<T, E>
+where
+    T: blocking::serial::Write<Word>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), E> {
+        self.inner.try_bwrite_all(buffer).map_err(Into::into)
+    }
+
+    fn try_bflush(&mut self) -> Result<(), E> {
+        self.inner.try_bflush().map_err(Into::into)
+    }
+}
+
+impl<T, E, UXX> DelayMs<UXX> for MapError<T, E>
+where
+    T: DelayMs<UXX>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_delay_ms(&mut self, ms: UXX) -> Result<(), E> {
+        self.inner.try_delay_ms(ms).map_err(Into::into)
+    }
+}
+
+impl<T, E, UXX> DelayUs<UXX> for MapError<T, E>
+where
+    T: DelayUs<UXX>,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_delay_us(&mut self, us: UXX) -> Result<(), E> {
+        self.inner.try_delay_us(us).map_err(Into::into)
+    }
+}
+
+impl<T, E> DelayNs for MapError<T, E>
+where
+    T: DelayNs,
+    T::Error: Into<E>,
+{
+    type Error = E;
+
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), E> {
+        self.inner.try_delay_ns(ns).map_err(Into::into)
+    }
+
+    fn try_delay_micros(&mut self, us: u32) -> Result<(), E> {
+        self.inner.try_delay_micros(us).map_err(Into::into)
+    }
+
+    fn try_delay_millis(&mut self, ms: u32) -> Result<(), E> {
+        self.inner.try_delay_millis(ms).map_err(Into::into)
+    }
+
+    fn try_delay<D>(&mut self, duration: D) -> Result<(), E>
+    where
+        D: Into<Duration>,
+    {
+        self.inner.try_delay(duration).map_err(Into::into)
+    }
+}
+
+impl<T, E> CountDown for MapError<T, E>
+where
+    T: CountDown,
+    T::Error: Into<E>,
+{
+    type Error = E;
+    type Time = T::Time;
+
+    fn try_start<C>(&mut self, count: C) -> Result<(), E>
+    where
+        C: Into<T::Time>,
+    {
+        self.inner.try_start(count).map_err(Into::into)
+    }
+
+    fn try_wait(&mut self) -> nb::Result<(), E> {
+        nb_into(self.inner.try_wait())
+    }
+}
@@ -0,0 +1,432 @@
+//! Fault injection
+//!
+//! [`Faulty`] wraps an SPI, I2C or serial implementation and makes some of its operations fail
+//! according to a [`Schedule`], so driver authors can test error handling paths that are
+//! otherwise hard to reproduce, e.g. NACKs or long runs of `WouldBlock`.
+//!
+//! Blocking operations fail with [`Error::Injected`]. Non-blocking operations can additionally
+//! be made to return `WouldBlock` without reaching the wrapped implementation.
+//!
+//! [`Faulty`]: struct.Faulty.html
+//! [`Schedule`]: enum.Schedule.html
+//! [`Error::Injected`]: enum.Error.html#variant.Injected
+//!
+//! # Examples
+//!
+//! Checking that a driver retries a write that was NACKed
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::Write;
+//! use embedded_hal::fault::{Error, Faults, Faulty, Schedule};
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//!
+//! // driver under test
+//! fn write_retrying<I: Write>(i2c: &mut I, bytes: &[u8]) -> Result<(), I::Error> {
+//!     match i2c.try_write(0x48, bytes) {
+//!         Ok(()) => Ok(()),
+//!         Err(_) => i2c.try_write(0x48, bytes),
+//!     }
+//! }
+//!
+//! let faults = Faults {
+//!     errors: Schedule::EveryNth(2),
+//!     ..Faults::default()
+//! };
+//! let mut i2c = Faulty::new(I2c, faults);
+//!
+//! write_retrying(&mut i2c, &[0x01]).unwrap();
+//! // the first attempt fails, the retry succeeds
+//! write_retrying(&mut i2c, &[0x02]).unwrap();
+//! assert_eq!(i2c.injected(), 1);
+//!
+//! assert_eq!(i2c.try_write(0x48, &[0x03]), Err(Error::Injected));
+//! ```
+
+use crate::blocking::{i2c, spi};
+use crate::serial;
+use crate::spi::FullDuplex;
+
+/// Error returned by a [`Faulty`] implementation
+///
+/// [`Faulty`]: struct.Faulty.html
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error<E> {
+    /// Error returned by the wrapped implementation
+    Inner(E),
+    /// Injected fault
+    Injected,
+}
+
+/// Selects the operations that fail
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Schedule {
+    /// No operation fails
+    Never,
+    /// Every `n`th operation fails
+    ///
+    /// `EveryNth(0)` behaves like `Never`.
+    EveryNth(u32),
+    /// On average one operation out of `one_in` fails, pseudo-randomly
+    ///
+    /// The same `seed` always produces the same sequence of faults, so failing tests can be
+    /// reproduced.
+    Random {
+        /// Seed of the pseudo-random generator
+        seed: u32,
+        /// Average number of operations per fault
+        one_in: u32,
+    },
+}
+
+/// Faults injected by a [`Faulty`] implementation
+///
+/// [`Faulty`]: struct.Faulty.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Faults {
+    /// Operations that fail with [`Error::Injected`]
+    ///
+    /// [`Error::Injected`]: enum.Error.html#variant.Injected
+    pub errors: Schedule,
+    /// Non-blocking operations that return `WouldBlock`
+    ///
+    /// This schedule is consulted before `errors`, on every call of a non-blocking method.
+    pub would_block: Schedule,
+}
+
+impl Default for Faults {
+    /// No faults
+    fn default() -> Self {
+        Faults {
+            errors: Schedule::Never,
+            would_block: Schedule::Never,
+        }
+    }
+}
+
+/// Schedule and its state
+struct Injector {
+    schedule: Schedule,
+    count: u32,
+    state: u32,
+}
+
+impl Injector {
+    fn new(schedule: Schedule) -> Self {
+        let state = match schedule {
+            // xorshift gets stuck on 0
+            Schedule::Random { seed, .. } => seed | 1,
+            _ => 0,
+        };
+
+        Injector {
+            schedule,
+            count: 0,
+            state,
+        }
+    }
+
+    fn inject(&mut self) -> bool {
+        match self.schedule {
+            Schedule::Never | Schedule::EveryNth(0) | Schedule::Random { one_in: 0, .. } => false,
+            Schedule::EveryNth(n) => {
+                self.count += 1;
+                if self.count == n {
+                    self.count = 0;
+                }
+                self.count == 0
+            }
+            Schedule::Random { one_in, .. } => {
+                self.state ^= self.state << 13;
+                self.state ^= self.state >> 17;
+                self.state ^= self.state << 5;
+                self.state <= 0xFFFF_FFFF / one_in
+            }
+        }
+    }
+}
+
+/// Implementation wrapper that injects faults
+pub struct Faulty<T> {
+    inner: T,
+    errors: Injector,
+    would_block: Injector,
+    injected: u32,
+}
+
+impl<T> Faulty<T> {
+    /// Wraps `inner`, injecting `faults`
+    pub fn new(inner: T, faults: Faults) -> Self {
+        Faulty {
+            inner,
+            errors: Injector::new(faults.errors),
+            would_block: Injector::new(faults.would_block),
+            injected: 0,
+        }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> T {
+        self.inner
+    }
+
+    /// Returns the number of faults injected so far, `WouldBlock`s included
+    pub fn injected(&self) -> u32 {
+        self.injected
+    }
+
+    fn error<E>(&mut self) -> Result<(), Error<E>> {
+        if self.errors.inject() {
+            self.injected += 1;
+            Err(Error::Injected)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn nb_error<E>(&mut self) -> nb::Result<(), Error<E>> {
+        if self.would_block.inject() {
+            self.injected += 1;
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.error().map_err(nb::Error::Other)
+    }
+}
+
+fn nb_inner<T, E>(result: nb::Result<T, E>) -> nb::Result<T, Error<E>> {
+    match result {
+        Ok(x) => Ok(x),
+        Err(nb::Error::Other(e)) => Err(nb::Error::Other(Error::Inner(e))),
+        Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
+    }
+}
+
+impl<T, W> FullDuplex<W> for Faulty<T>
+where
+    T: FullDuplex<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        self.nb_error()?;
+        nb_inner(self.inner.try_read())
+    }
+
+    fn try_send(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        self.nb_error()?;
+        nb_inner(self.inner.try_send(word))
+    }
+}
+
+impl<T, W> spi::Transfer<W> for Faulty<T>
+where
+    T: spi::Transfer<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], Self::Error> {
+        self.error()?;
+        self.inner.try_transfer(words).map_err(Error::Inner)
+    }
+}
+
+impl<T, W> spi::Write<W> for Faulty<T>
+where
+    T: spi::Write<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_write(words).map_err(Error::Inner)
+    }
+}
+
+impl<T, W> spi::WriteIter<W> for Faulty<T>
+where
+    T: spi::WriteIter<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = W>,
+    {
+        self.error()?;
+        self.inner.try_write_iter(words).map_err(Error::Inner)
+    }
+}
+
+impl<T, W> spi::Transactional<W> for Faulty<T>
+where
+    T: spi::Transactional<W>,
+    W: 'static,
+{
+    type Error = Error<T::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        operations: &mut [spi::Operation<'a, W>],
+    ) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_exec(operations).map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::Read<A> for Faulty<T>
+where
+    T: i2c::Read<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_read(address, buffer).map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::Write<A> for Faulty<T>
+where
+    T: i2c::Write<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_write(address, bytes).map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::WriteIter<A> for Faulty<T>
+where
+    T: i2c::WriteIter<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.error()?;
+        self.inner
+            .try_write_iter(address, bytes)
+            .map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::WriteRead<A> for Faulty<T>
+where
+    T: i2c::WriteRead<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner
+            .try_write_read(address, bytes, buffer)
+            .map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::WriteIterRead<A> for Faulty<T>
+where
+    T: i2c::WriteIterRead<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.error()?;
+        self.inner
+            .try_write_iter_read(address, bytes, buffer)
+            .map_err(Error::Inner)
+    }
+}
+
+impl<T, A> i2c::Transactional<A> for Faulty<T>
+where
+    T: i2c::Transactional<A>,
+    A: i2c::AddressMode,
+{
+    type Error = Error<T::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner
+            .try_exec(address, operations)
+            .map_err(Error::Inner)
+    }
+}
+
+impl<T, W> serial::Read<W> for Faulty<T>
+where
+    T: serial::Read<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        self.nb_error()?;
+        nb_inner(self.inner.try_read())
+    }
+}
+
+impl<T, W> serial::Write<W> for Faulty<T>
+where
+    T: serial::Write<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_write(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        self.nb_error()?;
+        nb_inner(self.inner.try_write(word))
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.nb_error()?;
+        nb_inner(self.inner.try_flush())
+    }
+}
+
+impl<T, W> crate::blocking::serial::Write<W> for Faulty<T>
+where
+    T: crate::blocking::serial::Write<W>,
+{
+    type Error = Error<T::Error>;
+
+    fn try_bwrite_all(&mut self, buffer: &[W]) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_bwrite_all(buffer).map_err(Error::Inner)
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        self.error()?;
+        self.inner.try_bflush().map_err(Error::Inner)
+    }
+}
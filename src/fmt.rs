@@ -1,8 +1,33 @@
-//! Implementation of `core::fmt::Write` for the HAL's `serial::Write`.
+//! Implementations of `core::fmt::Write` for the HAL's serial traits
 //!
-//! TODO write example of usage
+//! - `dyn serial::Write<Word>` implements `core::fmt::Write`, blocking on each word.
+//! - [`Writer`] implements it on top of a [`blocking::serial::Write<u8>`] implementation.
+//!
+//! [`Writer`]: struct.Writer.html
+//! [`blocking::serial::Write<u8>`]: ../blocking/serial/trait.Write.html
+//!
+//! # Examples
+//!
+//! ```
+//! use core::fmt::Write;
+//! use embedded_hal::fmt::Writer;
+//! # use core::convert::Infallible;
+//! # struct Usart1;
+//! # impl embedded_hal::blocking::serial::Write<u8> for Usart1 {
+//! #     type Error = Infallible;
+//! #     fn try_bwrite_all(&mut self, _: &[u8]) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_bflush(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//!
+//! let mut log = Writer::new(Usart1);
+//!
+//! writeln!(log, "boot #{}", 3).unwrap();
+//! ```
 use core::fmt::{Result, Write};
 
+use crate::blocking;
+
+#[cfg(feature = "nb")]
 impl<Word, Error> Write for dyn (crate::serial::Write<Word, Error = Error>)
 where
     Word: From<u8>,
@@ -16,3 +41,36 @@ where
         Ok(())
     }
 }
+
+/// `core::fmt::Write` implementation over a blocking serial writer
+///
+/// Errors of the serial writer are reported as `core::fmt::Error`; use
+/// [`try_write_fmt`] to get the error itself.
+///
+/// [`try_write_fmt`]: ../blocking/serial/trait.Write.html#method.try_write_fmt
+pub struct Writer<T> {
+    serial: T,
+}
+
+impl<T> Writer<T> {
+    /// Wraps the blocking `serial` writer
+    pub fn new(serial: T) -> Self {
+        Writer { serial }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> T {
+        self.serial
+    }
+}
+
+impl<T> Write for Writer<T>
+where
+    T: blocking::serial::Write<u8>,
+{
+    fn write_str(&mut self, s: &str) -> Result {
+        self.serial
+            .try_bwrite_all(s.as_bytes())
+            .map_err(|_| core::fmt::Error)
+    }
+}
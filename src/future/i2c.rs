@@ -0,0 +1,56 @@
+//! Asynchronous I2C master
+//!
+//! The I2C events of each operation are the same as the ones of the matching
+//! [`blocking::i2c`](../../blocking/i2c/index.html) trait.
+
+use core::future::Future;
+
+use crate::blocking::i2c::{AddressMode, SevenBitAddress};
+
+/// Asynchronous read
+pub trait Read<A: AddressMode = SevenBitAddress> {
+    /// An enumeration of I2C errors
+    type Error;
+
+    /// Future returned by `try_read`
+    type ReadFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Reads enough bytes from slave with `address` to fill `buffer`
+    fn try_read<'a>(&'a mut self, address: A, buffer: &'a mut [u8]) -> Self::ReadFuture<'a>;
+}
+
+/// Asynchronous write
+pub trait Write<A: AddressMode = SevenBitAddress> {
+    /// An enumeration of I2C errors
+    type Error;
+
+    /// Future returned by `try_write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Writes `bytes` to slave with `address`
+    fn try_write<'a>(&'a mut self, address: A, bytes: &'a [u8]) -> Self::WriteFuture<'a>;
+}
+
+/// Asynchronous write + read
+pub trait WriteRead<A: AddressMode = SevenBitAddress> {
+    /// An enumeration of I2C errors
+    type Error;
+
+    /// Future returned by `try_write_read`
+    type WriteReadFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Writes `bytes` to slave with `address` and then reads enough bytes to fill `buffer` *in a
+    /// single transaction*
+    fn try_write_read<'a>(
+        &'a mut self,
+        address: A,
+        bytes: &'a [u8],
+        buffer: &'a mut [u8],
+    ) -> Self::WriteReadFuture<'a>;
+}
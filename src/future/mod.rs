@@ -0,0 +1,35 @@
+//! Asynchronous traits
+//!
+//! The traits in this module return a [`Future`] that completes once the operation is over. The
+//! future is a generic associated type borrowing the peripheral and the buffers for the duration
+//! of the operation, so unlike the [`dma`] traits no `'static` buffer is needed: an async driver
+//! can hand buffers on its stack straight to the peripheral.
+//!
+//! This module requires the `async` feature and Rust 1.65.
+//!
+//! [`Future`]: https://doc.rust-lang.org/core/future/trait.Future.html
+//! [`dma`]: ../dma/index.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::future::i2c::WriteRead;
+//!
+//! const ADDRESS: u8 = 0x19;
+//! const OUT_X_L: u8 = 0x28;
+//!
+//! async fn read_acceleration<I: WriteRead>(i2c: &mut I) -> Result<[i16; 3], I::Error> {
+//!     let mut buffer = [0; 6];
+//!     i2c.try_write_read(ADDRESS, &[OUT_X_L | 0x80], &mut buffer).await?;
+//!
+//!     let mut acceleration = [0; 3];
+//!     for (axis, bytes) in acceleration.iter_mut().zip(buffer.chunks(2)) {
+//!         *axis = i16::from_le_bytes([bytes[0], bytes[1]]);
+//!     }
+//!     Ok(acceleration)
+//! }
+//! ```
+
+pub mod i2c;
+pub mod serial;
+pub mod spi;
@@ -0,0 +1,42 @@
+//! Asynchronous serial interface
+
+use core::future::Future;
+
+/// Asynchronous read half of a serial interface
+pub trait Read<Word: 'static = u8> {
+    /// An enumeration of serial errors
+    type Error;
+
+    /// Future returned by `try_read`
+    type ReadFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Reads enough words to fill `buffer`
+    fn try_read<'a>(&'a mut self, buffer: &'a mut [Word]) -> Self::ReadFuture<'a>;
+}
+
+/// Asynchronous write half of a serial interface
+pub trait Write<Word: 'static = u8> {
+    /// An enumeration of serial errors
+    type Error;
+
+    /// Future returned by `try_write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Future returned by `try_flush`
+    type FlushFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Writes all the `words`
+    ///
+    /// The future may complete once the words are buffered, before they have been sent; await
+    /// `try_flush` to make sure everything has been sent.
+    fn try_write<'a>(&'a mut self, words: &'a [Word]) -> Self::WriteFuture<'a>;
+
+    /// Completes once all the buffered words have been sent
+    fn try_flush<'a>(&'a mut self) -> Self::FlushFuture<'a>;
+}
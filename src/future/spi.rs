@@ -0,0 +1,31 @@
+//! Asynchronous SPI master
+
+use core::future::Future;
+
+/// Asynchronous transfer
+pub trait Transfer<W: 'static = u8> {
+    /// An enumeration of SPI errors
+    type Error;
+
+    /// Future returned by `try_transfer`
+    type TransferFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Sends `words` to the slave, replacing them with the words received from the slave
+    fn try_transfer<'a>(&'a mut self, words: &'a mut [W]) -> Self::TransferFuture<'a>;
+}
+
+/// Asynchronous write
+pub trait Write<W: 'static = u8> {
+    /// An enumeration of SPI errors
+    type Error;
+
+    /// Future returned by `try_write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Sends `words` to the slave, ignoring all the incoming words
+    fn try_write<'a>(&'a mut self, words: &'a [W]) -> Self::WriteFuture<'a>;
+}
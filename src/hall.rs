@@ -0,0 +1,58 @@
+//! Hall sensor interface
+//!
+//! Brushless DC motors often carry three hall sensors that together report the rotor position in
+//! one of six 60-degree sectors. In hall sensor mode, a timer XORs the three inputs and captures
+//! the time between two changes (commutation events), which gives both the position and the
+//! speed of the rotor.
+
+use nb;
+
+use crate::time::Duration;
+
+/// A commutation event
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Commutation {
+    /// State of the three hall inputs after the event, in the three low bits (`0bCBA`)
+    pub state: u8,
+    /// Time elapsed since the previous commutation event
+    pub interval: Duration,
+}
+
+/// Timer in hall sensor mode
+///
+/// # Examples
+///
+/// Six-step commutation of a BLDC motor
+///
+/// ```
+/// use embedded_hal::hall::HallSensor;
+///
+/// fn commutate<H: HallSensor>(hall: &mut H) -> Result<(), H::Error> {
+///     loop {
+///         let event = nb::block!(hall.try_wait_commutation())?;
+///
+///         // energize the phases matching `event.state`; `event.interval` is a sixth of an
+///         // electrical revolution
+/// #       break Ok(());
+///     }
+/// }
+/// ```
+pub trait HallSensor {
+    /// An enumeration of `HallSensor` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Returns the current state of the three hall inputs, in the three low bits (`0bCBA`)
+    fn try_state(&self) -> Result<u8, Self::Error>;
+
+    /// Waits for the next commutation event
+    ///
+    /// Returns `WouldBlock` until the state of the hall inputs changes.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if an event was missed, or if the timer overflowed because the
+    /// rotor is stalled.
+    fn try_wait_commutation(&mut self) -> nb::Result<Commutation, Self::Error>;
+}
@@ -0,0 +1,457 @@
+//! Bit-banged I2C master
+//!
+//! [`I2c`] drives an I2C bus from two GPIO pins and a delay provider, which allows creating extra
+//! I2C buses on any pair of pins.
+//!
+//! Both pins must be configured in open-drain mode with pull-up resistors on the bus: driving the
+//! pin high releases the line and reading the pin returns the actual state of the line. The SCL
+//! pin is read back after releasing it so that targets can stretch the clock.
+//!
+//! Only 7-bit addresses are supported; larger addresses are rejected with
+//! [`Error::InvalidAddress`]. Empty `Read` operations are skipped, since a read must NACK at least
+//! one byte to end properly, and an empty list of operations doesn't touch the bus at all.
+//!
+//! [`I2c`]: struct.I2c.html
+//! [`Error::InvalidAddress`]: enum.Error.html#variant.InvalidAddress
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::{Write, WriteRead};
+//! use embedded_hal::i2c::bitbang::{Error, I2c};
+//! use embedded_hal::i2c::Speed;
+//!
+//! let sda: OpenDrainPin = {
+//!     // ..
+//! #   OpenDrainPin
+//! };
+//! let scl: OpenDrainPin = {
+//!     // ..
+//! #   OpenDrainPin
+//! };
+//! let delay: Delay = {
+//!     // ..
+//! #   Delay
+//! };
+//!
+//! let mut i2c = I2c::new(sda, scl, delay, Speed::Standard);
+//!
+//! let mut id = [0];
+//! # let _ =
+//! i2c.try_write_read(0x19, &[0x0F], &mut id);
+//!
+//! assert_eq!(i2c.try_write(0x80, &[0x00]), Err(Error::InvalidAddress));
+//!
+//! # use core::convert::Infallible;
+//! # struct OpenDrainPin;
+//! # impl embedded_hal::digital::OutputPin for OpenDrainPin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! # impl embedded_hal::digital::InputPin for OpenDrainPin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Infallible> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Infallible> { Ok(false) }
+//! # }
+//! # struct Delay;
+//! # impl embedded_hal::blocking::delay::DelayNs for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_ns(&mut self, _: u32) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! ```
+
+use core::mem;
+
+use crate::blocking::delay::DelayNs;
+use crate::blocking::i2c::{
+    Operation, Read, SevenBitAddress, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+use crate::digital::{InputPin, OutputPin};
+use crate::i2c::{SetSpeed, Speed};
+
+/// Maximum time, in microseconds, a target is allowed to stretch the clock
+const CLOCK_STRETCH_TIMEOUT_US: u32 = 10_000;
+
+/// Bit-banged I2C error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error<E, DE> {
+    /// Error reported by the SDA or SCL pin
+    Pin(E),
+    /// Error reported by the delay provider
+    Delay(DE),
+    /// The target did not acknowledge the address or a data byte
+    Nack,
+    /// Another master took over the bus
+    ArbitrationLost,
+    /// The target held SCL low for longer than the clock stretching timeout
+    ClockStretchTimeout,
+    /// The address doesn't fit in 7 bits
+    InvalidAddress,
+}
+
+/// Bit-banged I2C master
+pub struct I2c<SDA, SCL, D> {
+    sda: SDA,
+    scl: SCL,
+    delay: D,
+    speed: Speed,
+}
+
+impl<SDA, SCL, D, E> I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    /// Creates a new I2C master from the open-drain `sda` and `scl` pins
+    ///
+    /// The bus runs at most at the maximum frequency of the `speed` mode; the actual frequency
+    /// depends on the resolution of the `delay` provider and on the cost of toggling the pins.
+    pub fn new(sda: SDA, scl: SCL, delay: D, speed: Speed) -> Self {
+        I2c {
+            sda,
+            scl,
+            delay,
+            speed,
+        }
+    }
+
+    /// Releases the pins and the delay provider
+    pub fn free(self) -> (SDA, SCL, D) {
+        (self.sda, self.scl, self.delay)
+    }
+
+    fn wait(&mut self) -> Result<(), Error<E, D::Error>> {
+        let half_period_ns = match self.speed {
+            Speed::Standard => 5_000,
+            Speed::Fast => 1_250,
+            Speed::FastPlus => 500,
+        };
+
+        self.delay
+            .try_delay_ns(half_period_ns)
+            .map_err(Error::Delay)
+    }
+
+    fn set_sda(&mut self, high: bool) -> Result<(), Error<E, D::Error>> {
+        if high {
+            self.sda.try_set_high().map_err(Error::Pin)
+        } else {
+            self.sda.try_set_low().map_err(Error::Pin)
+        }
+    }
+
+    fn scl_low(&mut self) -> Result<(), Error<E, D::Error>> {
+        self.scl.try_set_low().map_err(Error::Pin)
+    }
+
+    /// Releases SCL and waits for targets stretching the clock to release it as well
+    fn scl_high(&mut self) -> Result<(), Error<E, D::Error>> {
+        self.scl.try_set_high().map_err(Error::Pin)?;
+
+        let mut waited_us = 0;
+        while self.scl.try_is_low().map_err(Error::Pin)? {
+            if waited_us == CLOCK_STRETCH_TIMEOUT_US {
+                return Err(Error::ClockStretchTimeout);
+            }
+            self.delay.try_delay_micros(1).map_err(Error::Delay)?;
+            waited_us += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Sends a start condition, or a repeated start condition if the bus is already owned
+    fn start(&mut self) -> Result<(), Error<E, D::Error>> {
+        self.set_sda(true)?;
+        self.wait()?;
+        self.scl_high()?;
+        self.wait()?;
+        self.set_sda(false)?;
+        self.wait()?;
+        self.scl_low()
+    }
+
+    fn stop(&mut self) -> Result<(), Error<E, D::Error>> {
+        self.set_sda(false)?;
+        self.wait()?;
+        self.scl_high()?;
+        self.wait()?;
+        self.set_sda(true)?;
+        self.wait()
+    }
+
+    fn write_bit(&mut self, bit: bool) -> Result<(), Error<E, D::Error>> {
+        self.set_sda(bit)?;
+        self.wait()?;
+        self.scl_high()?;
+        if bit && self.sda.try_is_low().map_err(Error::Pin)? {
+            return Err(Error::ArbitrationLost);
+        }
+        self.wait()?;
+        self.scl_low()
+    }
+
+    fn read_bit(&mut self) -> Result<bool, Error<E, D::Error>> {
+        self.set_sda(true)?;
+        self.wait()?;
+        self.scl_high()?;
+        let bit = self.sda.try_is_high().map_err(Error::Pin)?;
+        self.wait()?;
+        self.scl_low()?;
+
+        Ok(bit)
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error<E, D::Error>> {
+        for i in (0..8).rev() {
+            self.write_bit(byte & (1 << i) != 0)?;
+        }
+
+        // the target acknowledges by pulling SDA low
+        if self.read_bit()? {
+            Err(Error::Nack)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_byte(&mut self, ack: bool) -> Result<u8, Error<E, D::Error>> {
+        let mut byte = 0;
+        for _ in 0..8 {
+            byte = (byte << 1) | self.read_bit()? as u8;
+        }
+        self.write_bit(!ack)?;
+
+        Ok(byte)
+    }
+
+    fn address(&mut self, address: u8, read: bool) -> Result<(), Error<E, D::Error>> {
+        self.start()?;
+        self.write_byte((address << 1) | read as u8)
+    }
+
+    fn exec(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation],
+    ) -> Result<(), Error<E, D::Error>> {
+        let mut previous = None;
+        for i in 0..operations.len() {
+            if is_empty_read(&operations[i]) {
+                continue;
+            }
+
+            let kind = mem::discriminant(&operations[i]);
+            let same_as_previous = previous == Some(kind);
+            let same_as_next = match operations[i + 1..].iter().find(|op| !is_empty_read(op)) {
+                Some(next) => mem::discriminant(next) == kind,
+                None => false,
+            };
+            previous = Some(kind);
+
+            match operations[i] {
+                Operation::Read(ref mut buffer) => {
+                    if !same_as_previous {
+                        self.address(address, true)?;
+                    }
+
+                    let len = buffer.len();
+                    for (j, byte) in buffer.iter_mut().enumerate() {
+                        // NACK the very last byte read before a stop or a repeated start
+                        *byte = self.read_byte(j + 1 < len || same_as_next)?;
+                    }
+                }
+                Operation::Write(buffer) => {
+                    if !same_as_previous {
+                        self.address(address, false)?;
+                    }
+
+                    for byte in buffer {
+                        self.write_byte(*byte)?;
+                    }
+                }
+            }
+        }
+
+        if previous.is_some() {
+            self.stop()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_iter_read<B>(
+        &mut self,
+        address: u8,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Error<E, D::Error>>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.address(address, false)?;
+        for byte in bytes {
+            self.write_byte(byte)?;
+        }
+
+        if !buffer.is_empty() {
+            self.address(address, true)?;
+            let len = buffer.len();
+            for (i, byte) in buffer.iter_mut().enumerate() {
+                *byte = self.read_byte(i + 1 < len)?;
+            }
+        }
+
+        self.stop()
+    }
+
+    /// Releases the bus after a failed transaction and returns the original error
+    fn recover<T>(
+        &mut self,
+        result: Result<T, Error<E, D::Error>>,
+    ) -> Result<T, Error<E, D::Error>> {
+        if result.is_err() {
+            // the original error is more relevant than a failure to release the bus
+            let _ = self.stop();
+        }
+
+        result
+    }
+}
+
+fn is_empty_read(operation: &Operation) -> bool {
+    match *operation {
+        Operation::Read(ref buffer) => buffer.is_empty(),
+        Operation::Write(_) => false,
+    }
+}
+
+fn check_address<E, DE>(address: u8) -> Result<(), Error<E, DE>> {
+    if address > 0x7F {
+        Err(Error::InvalidAddress)
+    } else {
+        Ok(())
+    }
+}
+
+impl<SDA, SCL, D, E> Transactional<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        check_address(address)?;
+        let result = self.exec(address, operations);
+        self.recover(result)
+    }
+}
+
+impl<SDA, SCL, D, E> Read<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.try_exec(address, &mut [Operation::Read(buffer)])
+    }
+}
+
+impl<SDA, SCL, D, E> Write<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.try_exec(addr, &mut [Operation::Write(bytes)])
+    }
+}
+
+impl<SDA, SCL, D, E> WriteRead<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.try_exec(
+            address,
+            &mut [Operation::Write(bytes), Operation::Read(buffer)],
+        )
+    }
+}
+
+impl<SDA, SCL, D, E> WriteIter<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_write_iter<B>(&mut self, addr: u8, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        check_address(addr)?;
+        let result = self.write_iter_read(addr, bytes, &mut []);
+        self.recover(result)
+    }
+}
+
+impl<SDA, SCL, D, E> WriteIterRead<SevenBitAddress> for I2c<SDA, SCL, D>
+where
+    SDA: OutputPin<Error = E> + InputPin<Error = E>,
+    SCL: OutputPin<Error = E> + InputPin<Error = E>,
+    D: DelayNs,
+{
+    type Error = Error<E, D::Error>;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: u8,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        check_address(address)?;
+        let result = self.write_iter_read(address, bytes, buffer);
+        self.recover(result)
+    }
+}
+
+impl<SDA, SCL, D> SetSpeed for I2c<SDA, SCL, D> {
+    type Error = core::convert::Infallible;
+
+    fn try_get_speed(&self) -> Result<Speed, Self::Error> {
+        Ok(self.speed)
+    }
+
+    fn try_set_speed(&mut self, speed: Speed) -> Result<(), Self::Error> {
+        self.speed = speed;
+        Ok(())
+    }
+}
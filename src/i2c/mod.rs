@@ -0,0 +1,208 @@
+//! Inter-Integrated Circuit (I2C)
+//!
+//! The blocking I2C master traits live in the [`blocking::i2c`] module.
+//!
+//! [`blocking::i2c`]: ../blocking/i2c/index.html
+
+#[cfg(feature = "nb")]
+use nb;
+
+pub mod bitbang;
+pub mod shared;
+
+use crate::blocking::i2c::{AddressMode, SevenBitAddress};
+
+/// Bus event observed by an I2C target
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// The master addressed this target with the read bit set; the data requested by the master
+    /// must be supplied with `try_write`
+    ReadRequest,
+    /// The master addressed this target with the write bit set; the bytes sent by the master will
+    /// be reported as `Received` events
+    WriteRequest,
+    /// A byte was written by the master
+    Received(u8),
+    /// The master issued a stop condition
+    Stop,
+}
+
+/// I2C target (slave) mode
+///
+/// # Examples
+///
+/// You can use this interface to emulate a small register file / EEPROM
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::i2c::{Event, Target};
+///
+/// fn serve<T: Target>(target: &mut T, memory: &mut [u8; 16]) -> Result<(), T::Error> {
+///     target.try_set_address(0x50)?;
+///
+///     let mut pointer = None;
+///     loop {
+///         match block!(target.try_listen())? {
+///             // the first byte written selects the register, the following ones are stored
+///             Event::Received(byte) => match pointer {
+///                 None => pointer = Some(usize::from(byte) % memory.len()),
+///                 Some(ref mut p) => {
+///                     memory[*p] = byte;
+///                     *p = (*p + 1) % memory.len();
+///                 }
+///             },
+///             Event::ReadRequest => {
+///                 let p = pointer.unwrap_or(0);
+///                 block!(target.try_write(memory[p]))?;
+///                 pointer = Some((p + 1) % memory.len());
+///             }
+///             Event::WriteRequest => pointer = None,
+///             Event::Stop => return Ok(()),
+///         }
+///     }
+/// }
+///
+/// # fn main() {}
+/// ```
+#[cfg(feature = "nb")]
+pub trait Target<A: AddressMode = SevenBitAddress> {
+    /// An enumeration of I2C target errors
+    type Error;
+
+    /// Sets the address this target responds to
+    fn try_set_address(&mut self, address: A) -> Result<(), Self::Error>;
+
+    /// "Waits" for the next bus event addressed to this target
+    ///
+    /// A `ReadRequest` event is reported again for every byte the master requests, until the
+    /// master ends the read with a stop (or repeated start) condition.
+    fn try_listen(&mut self) -> nb::Result<Event, Self::Error>;
+
+    /// Sends a byte to the master in response to a `ReadRequest`
+    fn try_write(&mut self, byte: u8) -> nb::Result<(), Self::Error>;
+}
+
+/// I2C bus speed mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Speed {
+    /// Standard mode, up to 100 kHz
+    Standard,
+    /// Fast mode, up to 400 kHz
+    Fast,
+    /// Fast mode plus, up to 1 MHz
+    FastPlus,
+}
+
+impl Speed {
+    /// Returns the maximum SCL clock frequency of this speed mode, in Hz
+    pub fn max_frequency(self) -> u32 {
+        match self {
+            Speed::Standard => 100_000,
+            Speed::Fast => 400_000,
+            Speed::FastPlus => 1_000_000,
+        }
+    }
+}
+
+/// Runtime configuration of the I2C bus speed
+///
+/// # Examples
+///
+/// Probe a device slowly before switching the bus to its full speed
+///
+/// ```
+/// use embedded_hal::blocking::i2c::Write;
+/// use embedded_hal::i2c::{SetSpeed, Speed};
+///
+/// fn init<I, E>(i2c: &mut I) -> Result<(), E>
+/// where
+///     I: SetSpeed<Error = E> + Write<Error = E>,
+/// {
+///     i2c.try_set_speed(Speed::Standard)?;
+///     i2c.try_write(0x3C, &[0xAE])?;
+///     i2c.try_set_speed(Speed::Fast)
+/// }
+/// ```
+pub trait SetSpeed {
+    /// An enumeration of speed configuration errors
+    ///
+    /// Possible errors:
+    ///
+    /// - the requested speed mode is not supported by the hardware
+    type Error;
+
+    /// Returns the current bus speed mode
+    fn try_get_speed(&self) -> Result<Speed, Self::Error>;
+
+    /// Sets a new bus speed mode
+    ///
+    /// The new speed applies to the transactions started after this call returns.
+    fn try_set_speed(&mut self, speed: Speed) -> Result<(), Self::Error>;
+}
+
+/// DMA-backed I2C write
+///
+/// # Examples
+///
+/// Stream a framebuffer to a display without blocking the CPU
+///
+/// ```
+/// use embedded_hal::dma::Transfer;
+/// use embedded_hal::i2c::WriteDma;
+///
+/// fn refresh<I: WriteDma>(i2c: I, framebuffer: &'static [u8]) -> I {
+///     let transfer = i2c.try_write_dma(0x3C, framebuffer).ok().unwrap();
+///
+///     // .. do other work while the framebuffer is being sent
+///
+///     let (_framebuffer, i2c) = transfer.try_wait().ok().unwrap();
+///     i2c
+/// }
+/// ```
+pub trait WriteDma<A: AddressMode = SevenBitAddress>: Sized {
+    /// An enumeration of errors that can occur while starting the transfer
+    type Error;
+
+    /// Handle to the ongoing transfer
+    ///
+    /// Errors that occur while the transfer is in progress (e.g. a NACK from the slave) are
+    /// reported by this handle.
+    type Transfer: crate::dma::Transfer<Output = (&'static [u8], Self)>;
+
+    /// Starts sending `buffer` to the slave with address `address`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as `blocking::i2c::Write`
+    fn try_write_dma(
+        self,
+        address: A,
+        buffer: &'static [u8],
+    ) -> Result<Self::Transfer, Self::Error>;
+}
+
+/// DMA-backed I2C read
+pub trait ReadDma<A: AddressMode = SevenBitAddress>: Sized {
+    /// An enumeration of errors that can occur while starting the transfer
+    type Error;
+
+    /// Handle to the ongoing transfer
+    ///
+    /// Errors that occur while the transfer is in progress (e.g. a NACK from the slave) are
+    /// reported by this handle.
+    type Transfer: crate::dma::Transfer<Output = (&'static mut [u8], Self)>;
+
+    /// Starts reading enough bytes from the slave with address `address` to fill `buffer`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as `blocking::i2c::Read`
+    fn try_read_dma(
+        self,
+        address: A,
+        buffer: &'static mut [u8],
+    ) -> Result<Self::Transfer, Self::Error>;
+}
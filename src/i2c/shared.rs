@@ -0,0 +1,190 @@
+//! Shared I2C bus
+//!
+//! Most boards connect several devices to the same I2C bus. The proxies in this module let each
+//! device driver own a handle to the bus while the bus itself stays in a single place. Every
+//! [`MutexDevice`] implements the blocking I2C traits by locking the shared bus and forwarding
+//! the call to it. The bus can be protected by any [`Mutex`]; aliases are provided for the
+//! mutexes implemented by this crate:
+//!
+//! - [`RefCellDevice`] shares the bus between drivers used from a single execution context (e.g.
+//!   the main loop). Using the bus from a second context while it is in use panics.
+//! - [`CriticalSectionDevice`] shares the bus across execution contexts (e.g. the main loop and
+//!   interrupt handlers) by running each bus operation inside a critical section. It requires the
+//!   `critical-section` feature.
+//!
+//! [`MutexDevice`]: struct.MutexDevice.html
+//! [`Mutex`]: ../../mutex/trait.Mutex.html
+//! [`RefCellDevice`]: type.RefCellDevice.html
+//! [`CriticalSectionDevice`]: type.CriticalSectionDevice.html
+//!
+//! # Examples
+//!
+//! ```
+//! use core::cell::RefCell;
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! use embedded_hal::i2c::shared::RefCellDevice;
+//!
+//! struct Sensor<I2C> {
+//!     i2c: I2C,
+//!     address: u8,
+//! }
+//!
+//! impl<I2C: WriteRead> Sensor<I2C> {
+//!     fn read_id(&mut self) -> Result<u8, I2C::Error> {
+//!         let mut id = [0];
+//!         self.i2c.try_write_read(self.address, &[0x0F], &mut id)?;
+//!         Ok(id[0])
+//!     }
+//! }
+//!
+//! let bus = RefCell::new(I2c1);
+//!
+//! let mut accelerometer = Sensor { i2c: RefCellDevice::new(&bus), address: 0x19 };
+//! let mut magnetometer = Sensor { i2c: RefCellDevice::new(&bus), address: 0x1E };
+//!
+//! accelerometer.read_id().unwrap();
+//! magnetometer.read_id().unwrap();
+//!
+//! # struct I2c1;
+//! # impl WriteRead for I2c1 {
+//! #     type Error = ();
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), ()> { Ok(()) }
+//! # }
+//! ```
+
+use core::cell::RefCell;
+
+use crate::blocking::i2c::{
+    AddressMode, Operation, Read, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+use crate::mutex::Mutex;
+
+/// Handle to an I2C bus shared through a `Mutex`
+pub struct MutexDevice<'a, M> {
+    bus: &'a M,
+}
+
+impl<'a, M> MutexDevice<'a, M>
+where
+    M: Mutex,
+{
+    /// Creates a new handle to the shared `bus`
+    pub fn new(bus: &'a M) -> Self {
+        MutexDevice { bus }
+    }
+}
+
+/// Handle to an I2C bus shared through a `RefCell`
+///
+/// # Panics
+///
+/// Bus operations panic if the bus is already borrowed, e.g. when a proxy is used from an
+/// interrupt handler while the main loop is in the middle of a transaction.
+pub type RefCellDevice<'a, T> = MutexDevice<'a, RefCell<T>>;
+
+/// Handle to an I2C bus shared through a critical section `Mutex`
+///
+/// Every bus operation is executed inside a critical section, so proxies can be used from
+/// different execution contexts.
+#[cfg(feature = "critical-section")]
+pub type CriticalSectionDevice<'a, T> = MutexDevice<'a, critical_section::Mutex<RefCell<T>>>;
+
+impl<'a, A, M> Read<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: Read<A>,
+{
+    type Error = <M::Data as Read<A>>::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.try_read(address, buffer))
+    }
+}
+
+impl<'a, A, M> Write<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: Write<A>,
+{
+    type Error = <M::Data as Write<A>>::Error;
+
+    fn try_write(&mut self, addr: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.try_write(addr, bytes))
+    }
+}
+
+impl<'a, A, M> WriteIter<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: WriteIter<A>,
+{
+    type Error = <M::Data as WriteIter<A>>::Error;
+
+    fn try_write_iter<B>(&mut self, addr: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.bus.lock(|bus| bus.try_write_iter(addr, bytes))
+    }
+}
+
+impl<'a, A, M> WriteRead<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: WriteRead<A>,
+{
+    type Error = <M::Data as WriteRead<A>>::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus
+            .lock(|bus| bus.try_write_read(address, bytes, buffer))
+    }
+}
+
+impl<'a, A, M> WriteIterRead<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: WriteIterRead<A>,
+{
+    type Error = <M::Data as WriteIterRead<A>>::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.bus
+            .lock(|bus| bus.try_write_iter_read(address, bytes, buffer))
+    }
+}
+
+impl<'a, A, M> Transactional<A> for MutexDevice<'a, M>
+where
+    A: AddressMode,
+    M: Mutex,
+    M::Data: Transactional<A>,
+{
+    type Error = <M::Data as Transactional<A>>::Error;
+
+    fn try_exec<'o>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'o>],
+    ) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.try_exec(address, operations))
+    }
+}
@@ -0,0 +1,208 @@
+//! Buffer oriented byte streams
+//!
+//! Unlike the word-at-a-time [`serial`] traits, these traits move as many bytes as possible per
+//! call, which is what protocol stacks, log sinks and DMA-backed UARTs want.
+//!
+//! Implementations of the `serial` traits get default implementations of these traits through
+//! the [`FromSerial`] wrapper.
+//!
+//! [`serial`]: ../serial/index.html
+//! [`FromSerial`]: struct.FromSerial.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::io::Write;
+//!
+//! // writes a log line, waiting as little as possible for the stream
+//! fn log<W: Write>(w: &mut W, mut line: &[u8]) -> Result<(), W::Error> {
+//!     while !line.is_empty() {
+//!         let n = nb::block!(w.try_write(line))?;
+//!         line = &line[n..];
+//!     }
+//!     Ok(())
+//! }
+//! # struct Uart;
+//! # impl Write for Uart {
+//! #     type Error = core::convert::Infallible;
+//! #     fn try_write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error> {
+//! #         Ok(buf.len().min(4))
+//! #     }
+//! #     fn try_flush(&mut self) -> nb::Result<(), Self::Error> { Ok(()) }
+//! # }
+//!
+//! log(&mut Uart, b"boot complete\r\n").unwrap();
+//! ```
+
+use crate::serial;
+use nb;
+
+/// Byte stream source
+pub trait Read {
+    /// An enumeration of `Read` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Reads some bytes into `buf`, returning how many were read
+    ///
+    /// # Contract
+    ///
+    /// - If `buf` is not empty, MUST return `WouldBlock` until it can read at least one byte.
+    /// - MUST NOT read more than `buf.len()` bytes.
+    fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error>;
+}
+
+impl<T> Read for &mut T
+where
+    T: Read + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error> {
+        T::try_read(self, buf)
+    }
+}
+
+/// Byte stream sink
+pub trait Write {
+    /// An enumeration of `Write` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Writes some bytes from `buf`, returning how many were written
+    ///
+    /// # Contract
+    ///
+    /// - If `buf` is not empty, MUST return `WouldBlock` until it can write at least one byte.
+    fn try_write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error>;
+
+    /// Ensures that all the bytes written so far have been sent
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+impl<T> Write for &mut T
+where
+    T: Write + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error> {
+        T::try_write(self, buf)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        T::try_flush(self)
+    }
+}
+
+/// Byte streams on top of a [`serial::Read`] / [`serial::Write`] implementation
+///
+/// Implements [`io::Read`] and [`io::Write`] by moving words until the wrapped implementation
+/// would block. The serial traits of the wrapped implementation remain available.
+///
+/// [`serial::Read`]: ../serial/trait.Read.html
+/// [`serial::Write`]: ../serial/trait.Write.html
+/// [`io::Read`]: trait.Read.html
+/// [`io::Write`]: trait.Write.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::io::{FromSerial, Write};
+/// # use core::convert::Infallible;
+/// # struct Usart1;
+/// # impl embedded_hal::serial::Write<u8> for Usart1 {
+/// #     type Error = Infallible;
+/// #     fn try_write(&mut self, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// #     fn try_flush(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+///
+/// let mut uart = FromSerial::new(Usart1);
+///
+/// assert_eq!(uart.try_write(b"boot complete\r\n"), Ok(15));
+/// ```
+pub struct FromSerial<S> {
+    serial: S,
+}
+
+impl<S> FromSerial<S> {
+    /// Wraps the word oriented `serial`
+    pub fn new(serial: S) -> Self {
+        FromSerial { serial }
+    }
+
+    /// Releases the wrapped implementation
+    pub fn free(self) -> S {
+        self.serial
+    }
+}
+
+impl<S, Word> serial::Read<Word> for FromSerial<S>
+where
+    S: serial::Read<Word>,
+{
+    type Error = S::Error;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        self.serial.try_read()
+    }
+}
+
+impl<S, Word> serial::Write<Word> for FromSerial<S>
+where
+    S: serial::Write<Word>,
+{
+    type Error = S::Error;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        self.serial.try_write(word)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.serial.try_flush()
+    }
+}
+
+impl<S> Read for FromSerial<S>
+where
+    S: serial::Read<u8>,
+{
+    type Error = S::Error;
+
+    fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error> {
+        for (i, byte) in buf.iter_mut().enumerate() {
+            match self.serial.try_read() {
+                Ok(b) => *byte = b,
+                Err(nb::Error::WouldBlock) if i > 0 => return Ok(i),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(buf.len())
+    }
+}
+
+impl<S> Write for FromSerial<S>
+where
+    S: serial::Write<u8>,
+{
+    type Error = S::Error;
+
+    fn try_write(&mut self, buf: &[u8]) -> nb::Result<usize, Self::Error> {
+        for (i, &byte) in buf.iter().enumerate() {
+            match self.serial.try_write(byte) {
+                Ok(()) => {}
+                Err(nb::Error::WouldBlock) if i > 0 => return Ok(i),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.serial.try_flush()
+    }
+}
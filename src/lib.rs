@@ -51,6 +51,31 @@
 //! embedded-hal keyword](https://doc.rust-lang.org/cargo/reference/manifest.html#package-metadata)
 //! to your crate before publishing it!
 //!
+//! # Cargo features
+//!
+//! The digital, SPI, I2C, timer and delay traits are always available, apart from the non-blocking
+//! ones. Each other peripheral module is gated behind a feature of the same name, with dashes
+//! instead of underscores: `adc`, `can`, `capture`, `cec`, `clock`, `hall`, `memory-bus`, `net`,
+//! `power`, `probe`, `pwm`, `qei`, `rng`, `rtc`, `segment-lcd`, `sigma-delta`, `ssi`, `storage`
+//! and `watchdog`. They are all enabled by default; drivers should disable the default features and
+//! only enable the modules they use.
+//!
+//! - `nb`, enabled by default, enables the non-blocking traits, the [`nb`](nb/index.html) module
+//!   and the `nb` crate dependency, along with the `io` and `serial` modules and the blocking
+//!   wrappers built on them. The `adc`, `can`, `capture`, `cec`, `hall`, `net`, `rng`,
+//!   `segment-lcd` and `sigma-delta` features imply it, as do `dynamic`, `fault`, `trace`, `mock`,
+//!   `std` and `testsuite`.
+//! - `alloc` implements the traits for `Box<T>`.
+//! - `async` enables the [`future`](future/index.html) traits, which require Rust 1.65, and the
+//!   experimental [`poll`](poll/index.html) traits.
+//! - `std` enables the [`std_io`](std_io/index.html) bridges.
+//! - `dynamic` enables the [`dynamic`](dynamic/index.html) trait object helpers.
+//! - `fault` and `trace` enable the [`Faulty`](fault/struct.Faulty.html) and
+//!   [`Traced`](trace/struct.Traced.html) wrappers.
+//! - `mock` enables the [`mock`](mock/index.html) implementations.
+//! - `testsuite` enables the [`testsuite`](testsuite/index.html) checks.
+//! - `critical-section` enables the critical section based bus sharing.
+//!
 //! # Detailed design
 //!
 //! ## Traits
@@ -193,6 +218,10 @@
 //! [`try_nb!`]: https://docs.rs/nb/0.1.0/nb/index.html#how-to-use-this-crate
 //! [`await!`]: https://docs.rs/nb/0.1.0/nb/index.html#how-to-use-this-crate
 //!
+//! Drivers that simply wait for each operation to complete should depend on the
+//! [`blocking`](blocking/index.html) traits. The non-blocking traits are gathered in the
+//! [`nb`](nb/index.html) module for code that schedules the polling itself.
+//!
 //! ### Blocking mode
 //!
 //! An example of sending a string over the serial interface in a blocking
@@ -204,6 +233,7 @@
 //! extern crate nb;
 //!
 //! use stm32f30x_hal::Serial1;
+//! # #[cfg(feature = "nb")]
 //! use embedded_hal::serial::Write;
 //!
 //! # fn main() {
@@ -484,6 +514,7 @@
 //!
 //! use hal::prelude::*;
 //!
+//! # #[cfg(feature = "nb")]
 //! fn write_all<S>(serial: &mut S, buffer: &[u8]) -> Result<(), S::Error>
 //! where
 //!     S: hal::serial::Write<u8>
@@ -513,6 +544,7 @@
 //!     TimedOut(TE),
 //! }
 //!
+//! # #[cfg(feature = "nb")]
 //! fn read_with_timeout<S, T>(
 //!     serial: &mut S,
 //!     timer: &mut T,
@@ -599,6 +631,7 @@
 //! use hal::prelude::*;
 //! use ::core::convert::Infallible;
 //!
+//! # #[cfg(feature = "nb")]
 //! fn flush<S>(serial: &mut S, cb: &mut CircularBuffer)
 //! where
 //!     S: hal::serial::Write<u8, Error = Infallible>,
@@ -626,6 +659,7 @@
 //!     // ..
 //! #   Mutex(CircularBuffer)
 //! };
+//! # #[cfg(feature = "nb")]
 //! static SERIAL1: Mutex<Serial1> = {
 //!     // ..
 //! #   Mutex(Serial1)
@@ -645,6 +679,7 @@
 //!     }
 //! }
 //!
+//! # #[cfg(feature = "nb")]
 //! fn interrupt_handler() {
 //!     let mut serial = SERIAL1.lock();
 //!     let mut buffer = BUFFER1.lock();
@@ -665,6 +700,7 @@
 //! #     fn deref_mut(&mut self) -> &mut T { self.0 }
 //! # }
 //! # struct Serial1;
+//! # #[cfg(feature = "nb")]
 //! # impl ::hal::serial::Write<u8> for Serial1 {
 //! #   type Error = Infallible;
 //! #   fn try_write(&mut self, _: u8) -> nb::Result<(), Infallible> { Err(::nb::Error::WouldBlock) }
@@ -683,19 +719,96 @@
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "nb")]
 #[macro_use]
-extern crate nb;
+extern crate nb as _;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "adc")]
 pub mod adc;
 pub mod blocking;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "can")]
+pub mod can;
+#[cfg(feature = "capture")]
 pub mod capture;
+#[cfg(feature = "cec")]
+pub mod cec;
+#[cfg(feature = "clock")]
+pub mod clock;
 pub mod digital;
+pub mod dma;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(feature = "fault")]
+pub mod fault;
 pub mod fmt;
+#[cfg(feature = "async")]
+pub mod future;
+#[cfg(feature = "hall")]
+pub mod hall;
+pub mod i2c;
+#[cfg(feature = "nb")]
+pub mod io;
+#[cfg(feature = "memory-bus")]
+pub mod memory_bus;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod mutex;
+#[cfg(feature = "nb")]
+pub mod nb;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "async")]
+pub mod poll;
+#[cfg(feature = "power")]
+pub mod power;
 pub mod prelude;
+pub mod preludes;
+#[cfg(feature = "probe")]
+pub mod probe;
+#[cfg(feature = "pwm")]
 pub mod pwm;
+#[cfg(feature = "qei")]
 pub mod qei;
+#[cfg(feature = "rng")]
 pub mod rng;
+#[cfg(feature = "rtc")]
+pub mod rtc;
+#[cfg(feature = "segment-lcd")]
+pub mod segment_lcd;
+#[cfg(feature = "nb")]
 pub mod serial;
+#[cfg(feature = "sigma-delta")]
+pub mod sigma_delta;
 pub mod spi;
+#[cfg(feature = "ssi")]
+pub mod ssi;
+#[cfg(feature = "std")]
+pub mod std_io;
+#[cfg(feature = "storage")]
+pub mod storage;
+#[cfg(feature = "testsuite")]
+pub mod testsuite;
+pub mod time;
 pub mod timer;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "watchdog")]
 pub mod watchdog;
+
+mod private {
+    use crate::blocking::i2c::{SevenBitAddress, TenBitAddress};
+    pub trait Sealed {}
+
+    impl Sealed for SevenBitAddress {}
+    impl Sealed for TenBitAddress {}
+}
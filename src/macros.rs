@@ -0,0 +1,175 @@
+/// Implements the blocking traits of a peripheral on top of its non-blocking traits
+///
+/// The blocking serial and SPI traits have default implementations built on the `nb` traits,
+/// provided by the [`BlockingSerial`] and [`BlockingSpi`] wrappers, and the `io` traits by the
+/// [`FromSerial`] wrapper. This macro implements them directly on a peripheral, so it can be used
+/// in both modes without being wrapped:
+///
+/// - `serial<Word> for T` implements [`blocking::serial::Write<Word>`] for a
+///   [`serial::Write<Word>`] implementation.
+/// - `spi<Word> for T` implements the blocking SPI [`Transfer<Word>`], [`Write<Word>`],
+///   [`WriteIter<Word>`] and [`Transactional<Word>`] traits for a [`FullDuplex<Word>`]
+///   implementation.
+/// - `io for T` implements [`io::Read`] and [`io::Write`] for a type implementing
+///   [`serial::Read<u8>`] and [`serial::Write<u8>`].
+///
+/// Generic implementations are prefixed with `impl<..>` and may end with a `where` clause.
+///
+/// [`BlockingSerial`]: blocking/serial/struct.BlockingSerial.html
+/// [`BlockingSpi`]: blocking/spi/struct.BlockingSpi.html
+/// [`FromSerial`]: io/struct.FromSerial.html
+/// [`blocking::serial::Write<Word>`]: blocking/serial/trait.Write.html
+/// [`serial::Write<Word>`]: serial/trait.Write.html
+/// [`Transfer<Word>`]: blocking/spi/trait.Transfer.html
+/// [`Write<Word>`]: blocking/spi/trait.Write.html
+/// [`WriteIter<Word>`]: blocking/spi/trait.WriteIter.html
+/// [`Transactional<Word>`]: blocking/spi/trait.Transactional.html
+/// [`FullDuplex<Word>`]: spi/trait.FullDuplex.html
+/// [`io::Read`]: io/trait.Read.html
+/// [`io::Write`]: io/trait.Write.html
+/// [`serial::Read<u8>`]: serial/trait.Read.html
+/// [`serial::Write<u8>`]: serial/trait.Write.html
+///
+/// # Examples
+///
+/// ```
+/// use core::marker::PhantomData;
+/// use embedded_hal::blocking::spi::Transfer;
+/// use embedded_hal::impl_blocking_from_nb;
+///
+/// pub struct Spi<SPI> {
+///     spi: PhantomData<SPI>,
+/// }
+///
+/// pub struct Usart1;
+///
+/// impl_blocking_from_nb!(impl<SPI> spi<u8> for Spi<SPI> where SPI: Instance);
+/// impl_blocking_from_nb!(serial<u8> for Usart1);
+/// impl_blocking_from_nb!(io for Usart1);
+///
+/// let mut spi = Spi::<Spi1> { spi: PhantomData };
+/// spi.try_transfer(&mut [0x9F, 0, 0]).unwrap();
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::{serial, spi::FullDuplex};
+/// # pub trait Instance {}
+/// # pub struct Spi1;
+/// # impl Instance for Spi1 {}
+/// # impl<SPI: Instance> FullDuplex<u8> for Spi<SPI> {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self) -> nb::Result<u8, Infallible> { Ok(0) }
+/// #     fn try_send(&mut self, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// # impl serial::Read<u8> for Usart1 {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self) -> nb::Result<u8, Infallible> { Ok(0) }
+/// # }
+/// # impl serial::Write<u8> for Usart1 {
+/// #     type Error = Infallible;
+/// #     fn try_write(&mut self, _: u8) -> nb::Result<(), Infallible> { Ok(()) }
+/// #     fn try_flush(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+#[cfg(feature = "nb")]
+#[macro_export]
+macro_rules! impl_blocking_from_nb {
+    (serial<$word:ty> for $ty:ty $(where $($wc:tt)*)?) => {
+        $crate::impl_blocking_from_nb!(impl<> serial<$word> for $ty $(where $($wc)*)?);
+    };
+    (spi<$word:ty> for $ty:ty $(where $($wc:tt)*)?) => {
+        $crate::impl_blocking_from_nb!(impl<> spi<$word> for $ty $(where $($wc)*)?);
+    };
+    (io for $ty:ty $(where $($wc:tt)*)?) => {
+        $crate::impl_blocking_from_nb!(impl<> io for $ty $(where $($wc)*)?);
+    };
+    (impl<$($g:ident),*> serial<$word:ty> for $ty:ty $(where $($wc:tt)*)?) => {
+        impl<$($g),*> $crate::blocking::serial::Write<$word> for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::serial::Write<$word>>::Error;
+
+            fn try_bwrite_all(&mut self, buffer: &[$word]) -> Result<(), Self::Error> {
+                $crate::blocking::serial::BlockingSerial::new(self).try_bwrite_all(buffer)
+            }
+
+            fn try_bflush(&mut self) -> Result<(), Self::Error> {
+                $crate::blocking::serial::BlockingSerial::new(self).try_bflush()
+            }
+        }
+    };
+    (impl<$($g:ident),*> spi<$word:ty> for $ty:ty $(where $($wc:tt)*)?) => {
+        impl<$($g),*> $crate::blocking::spi::Transfer<$word> for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::spi::FullDuplex<$word>>::Error;
+
+            fn try_transfer<'w>(
+                &mut self,
+                words: &'w mut [$word],
+            ) -> Result<&'w [$word], Self::Error> {
+                $crate::blocking::spi::BlockingSpi::new(self).try_transfer(words)
+            }
+        }
+
+        impl<$($g),*> $crate::blocking::spi::Write<$word> for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::spi::FullDuplex<$word>>::Error;
+
+            fn try_write(&mut self, words: &[$word]) -> Result<(), Self::Error> {
+                $crate::blocking::spi::BlockingSpi::new(self).try_write(words)
+            }
+        }
+
+        impl<$($g),*> $crate::blocking::spi::WriteIter<$word> for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::spi::FullDuplex<$word>>::Error;
+
+            fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+            where
+                WI: IntoIterator<Item = $word>,
+            {
+                $crate::blocking::spi::BlockingSpi::new(self).try_write_iter(words)
+            }
+        }
+
+        impl<$($g),*> $crate::blocking::spi::Transactional<$word> for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::spi::FullDuplex<$word>>::Error;
+
+            fn try_exec<'a>(
+                &mut self,
+                operations: &mut [$crate::blocking::spi::Operation<'a, $word>],
+            ) -> Result<(), Self::Error> {
+                $crate::blocking::spi::BlockingSpi::new(self).try_exec(operations)
+            }
+        }
+    };
+    (impl<$($g:ident),*> io for $ty:ty $(where $($wc:tt)*)?) => {
+        impl<$($g),*> $crate::io::Read for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::serial::Read<u8>>::Error;
+
+            fn try_read(&mut self, buf: &mut [u8]) -> $crate::nb::Result<usize, Self::Error> {
+                $crate::io::Read::try_read(&mut $crate::io::FromSerial::new(self), buf)
+            }
+        }
+
+        impl<$($g),*> $crate::io::Write for $ty
+        $(where $($wc)*)?
+        {
+            type Error = <$ty as $crate::serial::Write<u8>>::Error;
+
+            fn try_write(&mut self, buf: &[u8]) -> $crate::nb::Result<usize, Self::Error> {
+                $crate::io::Write::try_write(&mut $crate::io::FromSerial::new(self), buf)
+            }
+
+            fn try_flush(&mut self) -> $crate::nb::Result<(), Self::Error> {
+                $crate::io::Write::try_flush(&mut $crate::io::FromSerial::new(self))
+            }
+        }
+    };
+}
@@ -0,0 +1,72 @@
+//! External memory bus
+//!
+//! External bus controllers (FSMC, FMC, EBI, EMC, ...) map the memory or device connected to a
+//! chip select into the address space of the processor. [`MemoryBus`] gives drivers access to
+//! such a region by offset, so SRAM, NOR / NAND flash and parallel (8080 / 6800) display drivers
+//! can be written once for every external bus controller.
+//!
+//! [`MemoryBus`]: trait.MemoryBus.html
+
+/// Region of an external memory bus
+///
+/// `Word` is the width of the accesses, e.g. `u16` for a 16-bit data bus. An implementation
+/// represents the region of a single chip select, with offsets relative to its start, in bytes.
+///
+/// # Examples
+///
+/// A parallel display controller whose register select pin is wired to address line A16: a
+/// write below `DATA` is a command, a write at `DATA` is a parameter.
+///
+/// ```
+/// use embedded_hal::memory_bus::MemoryBus;
+///
+/// const COMMAND: u32 = 0;
+/// // A16 on a 16-bit bus is at byte offset 1 << 17
+/// const DATA: u32 = 1 << 17;
+///
+/// fn set_column_range<B: MemoryBus<u16>>(bus: &mut B, start: u16, end: u16) -> Result<(), B::Error> {
+///     bus.try_write(COMMAND, 0x2A)?;
+///     bus.try_write_words(DATA, &[start >> 8, start & 0xFF, end >> 8, end & 0xFF])
+/// }
+/// ```
+pub trait MemoryBus<Word> {
+    /// An enumeration of bus errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of bounds*, the access goes past the end of the region
+    /// - *misaligned*, `offset` is not a multiple of the size of `Word`
+    type Error;
+
+    /// Reads the word at `offset`
+    fn try_read(&mut self, offset: u32) -> Result<Word, Self::Error>;
+
+    /// Writes `word` at `offset`
+    fn try_write(&mut self, offset: u32, word: Word) -> Result<(), Self::Error>;
+
+    /// Reads `words.len()` words from `offset`
+    ///
+    /// All the words are read at the same `offset`, as needed by FIFO-like devices such as NAND
+    /// flash or display controllers. Use `try_read` in a loop to read consecutive words.
+    fn try_read_words(&mut self, offset: u32, words: &mut [Word]) -> Result<(), Self::Error> {
+        for word in words {
+            *word = self.try_read(offset)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `words` at `offset`
+    ///
+    /// All the words are written at the same `offset`, see `try_read_words`.
+    fn try_write_words(&mut self, offset: u32, words: &[Word]) -> Result<(), Self::Error>
+    where
+        Word: Copy,
+    {
+        for &word in words {
+            self.try_write(offset, word)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+//! ADC mock
+//!
+//! Channels are identified by a `u8`; use an [`AnyChannel`] to sample them.
+//!
+//! [`AnyChannel`]: ../../adc/struct.AnyChannel.html
+//!
+//! ```
+//! use embedded_hal::adc::{AnyChannel, OneShot};
+//! use embedded_hal::mock::adc::{Mock, Transaction};
+//!
+//! let mut adc = Mock::new(&[Transaction::read(3, 2048)]);
+//!
+//! let mut pin = AnyChannel::new(3);
+//! let sample: u16 = adc.try_read(&mut pin).unwrap();
+//! assert_eq!(sample, 2048);
+//!
+//! adc.done();
+//! ```
+
+use core::convert::Infallible;
+
+use super::Expectations;
+use crate::adc::{Channel, OneShot};
+
+/// An ADC conversion
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Transaction {
+    /// Channel converted
+    pub channel: u8,
+    /// Result of the conversion
+    pub value: u16,
+}
+
+impl Transaction {
+    /// Expects a conversion of `channel` and returns `value`
+    pub fn read(channel: u8, value: u16) -> Self {
+        Transaction { channel, value }
+    }
+}
+
+/// ADC mock
+pub struct Mock {
+    expectations: Expectations<Transaction>,
+}
+
+impl Mock {
+    /// Creates a mock expecting the `expected` transactions, in order
+    pub fn new(expected: &[Transaction]) -> Self {
+        Mock {
+            expectations: Expectations::new(expected),
+        }
+    }
+
+    /// Checks that all the expected transactions were performed
+    pub fn done(&mut self) {
+        self.expectations.done();
+    }
+}
+
+impl<PIN> OneShot<Mock, u16, PIN> for Mock
+where
+    PIN: Channel<Mock, ID = u8>,
+{
+    type Error = Infallible;
+
+    fn try_read(&mut self, pin: &mut PIN) -> nb::Result<u16, Self::Error> {
+        let channel = pin.channel();
+        let expected = self.expectations.next(&channel);
+        assert_eq!(channel, expected.channel, "ADC channel");
+
+        Ok(expected.value)
+    }
+}
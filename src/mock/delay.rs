@@ -0,0 +1,72 @@
+//! Delay mock
+//!
+//! The mock doesn't wait: it adds up the requested delays so tests can check them.
+//!
+//! ```
+//! use embedded_hal::blocking::delay::DelayNs;
+//! use embedded_hal::mock::delay::Mock;
+//! use embedded_hal::time::Duration;
+//!
+//! let mut delay = Mock::new();
+//!
+//! delay.try_delay_micros(10).unwrap();
+//! delay.try_delay_millis(5).unwrap();
+//!
+//! assert_eq!(delay.elapsed(), Duration::from_micros(5_010));
+//! ```
+
+use core::convert::Infallible;
+
+use crate::blocking::delay::{DelayMs, DelayNs, DelayUs};
+use crate::time::Duration;
+
+/// Delay mock
+#[derive(Debug, Default)]
+pub struct Mock {
+    elapsed: Duration,
+}
+
+impl Mock {
+    /// Creates a new mock
+    pub fn new() -> Self {
+        Mock::default()
+    }
+
+    /// Returns the sum of all the delays requested so far
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl DelayNs for Mock {
+    type Error = Infallible;
+
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        self.elapsed += Duration::from_nanos(u64::from(ns));
+        Ok(())
+    }
+}
+
+impl<UXX> DelayMs<UXX> for Mock
+where
+    UXX: Into<u64>,
+{
+    type Error = Infallible;
+
+    fn try_delay_ms(&mut self, ms: UXX) -> Result<(), Self::Error> {
+        self.elapsed += Duration::from_millis(ms.into());
+        Ok(())
+    }
+}
+
+impl<UXX> DelayUs<UXX> for Mock
+where
+    UXX: Into<u64>,
+{
+    type Error = Infallible;
+
+    fn try_delay_us(&mut self, us: UXX) -> Result<(), Self::Error> {
+        self.elapsed += Duration::from_micros(us.into());
+        Ok(())
+    }
+}
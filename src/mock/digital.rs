@@ -0,0 +1,99 @@
+//! GPIO pin mock
+//!
+//! ```
+//! use embedded_hal::digital::{InputPin, OutputPin};
+//! use embedded_hal::mock::digital::{Mock, State, Transaction};
+//!
+//! let mut pin = Mock::new(&[
+//!     Transaction::Set(State::High),
+//!     Transaction::Get(State::Low),
+//! ]);
+//!
+//! pin.try_set_high().unwrap();
+//! assert!(pin.try_is_low().unwrap());
+//!
+//! pin.done();
+//! ```
+
+use core::convert::Infallible;
+
+use super::Expectations;
+use crate::digital::{InputPin, OutputPin};
+
+/// Level of a pin
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    /// Low level
+    Low,
+    /// High level
+    High,
+}
+
+/// A pin transaction
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Transaction {
+    /// Read of the pin, answered with the given level
+    Get(State),
+    /// Setting of the pin to the given level
+    Set(State),
+}
+
+/// GPIO pin mock
+pub struct Mock {
+    expectations: Expectations<Transaction>,
+}
+
+impl Mock {
+    /// Creates a mock expecting the `expected` transactions, in order
+    pub fn new(expected: &[Transaction]) -> Self {
+        Mock {
+            expectations: Expectations::new(expected),
+        }
+    }
+
+    /// Checks that all the expected transactions were performed
+    pub fn done(&mut self) {
+        self.expectations.done();
+    }
+
+    fn get(&self) -> State {
+        match self.expectations.next(&"get") {
+            Transaction::Get(state) => state,
+            expected => panic!("expected {:?}, got a read of the pin", expected),
+        }
+    }
+
+    fn set(&mut self, state: State) {
+        let actual = Transaction::Set(state);
+        match self.expectations.next(&actual) {
+            Transaction::Set(expected) => assert_eq!(state, expected, "pin level"),
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+    }
+}
+
+impl InputPin for Mock {
+    type Error = Infallible;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.get() == State::High)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.get() == State::Low)
+    }
+}
+
+impl OutputPin for Mock {
+    type Error = Infallible;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.set(State::Low);
+        Ok(())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.set(State::High);
+        Ok(())
+    }
+}
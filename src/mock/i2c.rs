@@ -0,0 +1,307 @@
+//! I2C mock
+//!
+//! Only 7-bit addresses are supported.
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::{Read, Write};
+//! use embedded_hal::mock::i2c::{Mock, Transaction};
+//!
+//! let mut i2c = Mock::new(&[
+//!     Transaction::write(0x48, &[0x01, 0x60]),
+//!     Transaction::read(0x48, &[0x12, 0x34]),
+//! ]);
+//!
+//! i2c.try_write(0x48, &[0x01, 0x60]).unwrap();
+//!
+//! let mut buffer = [0; 2];
+//! i2c.try_read(0x48, &mut buffer).unwrap();
+//! assert_eq!(buffer, [0x12, 0x34]);
+//!
+//! i2c.done();
+//! ```
+//!
+//! Transactional operations are expected as a whole with `Transaction::exec`
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::{self, Transactional};
+//! use embedded_hal::mock::i2c::{Mock, Operation, Transaction};
+//!
+//! let mut i2c = Mock::new(&[Transaction::exec(
+//!     0x48,
+//!     &[Operation::write(&[0x00]), Operation::read(&[0x12, 0x34])],
+//! )]);
+//!
+//! let mut buffer = [0; 2];
+//! i2c.try_exec(0x48, &mut [i2c::Operation::Write(&[0x00]), i2c::Operation::Read(&mut buffer)])
+//!     .unwrap();
+//! assert_eq!(buffer, [0x12, 0x34]);
+//!
+//! i2c.done();
+//! ```
+
+use core::convert::Infallible;
+use std::vec::Vec;
+
+use super::Expectations;
+use crate::blocking::i2c::{
+    self, Read, SevenBitAddress, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+
+/// An I2C transaction
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Transaction {
+    /// Read from `address`, answered with `data`
+    Read {
+        /// Address of the target
+        address: u8,
+        /// Data returned by the target
+        data: Vec<u8>,
+    },
+    /// Write of `data` to `address`
+    Write {
+        /// Address of the target
+        address: u8,
+        /// Data sent to the target
+        data: Vec<u8>,
+    },
+    /// Write of `write` to `address` followed by a read, answered with `read`
+    WriteRead {
+        /// Address of the target
+        address: u8,
+        /// Data sent to the target
+        write: Vec<u8>,
+        /// Data returned by the target
+        read: Vec<u8>,
+    },
+    /// Execution of `operations` on `address`, through `Transactional`
+    Exec {
+        /// Address of the target
+        address: u8,
+        /// Operations, in order
+        operations: Vec<Operation>,
+    },
+}
+
+/// An operation of a `Transaction::Exec`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Read, answered with the given data
+    Read(Vec<u8>),
+    /// Write of the given data
+    Write(Vec<u8>),
+}
+
+impl Operation {
+    /// Expects a read and answers with `data`
+    pub fn read(data: &[u8]) -> Self {
+        Operation::Read(data.to_vec())
+    }
+
+    /// Expects a write of `data`
+    pub fn write(data: &[u8]) -> Self {
+        Operation::Write(data.to_vec())
+    }
+}
+
+impl Transaction {
+    /// Expects a read from `address` and answers with `data`
+    pub fn read(address: u8, data: &[u8]) -> Self {
+        Transaction::Read {
+            address,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Expects a write of `data` to `address`
+    pub fn write(address: u8, data: &[u8]) -> Self {
+        Transaction::Write {
+            address,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Expects a write of `write` to `address` followed by a read, and answers with `read`
+    pub fn write_read(address: u8, write: &[u8], read: &[u8]) -> Self {
+        Transaction::WriteRead {
+            address,
+            write: write.to_vec(),
+            read: read.to_vec(),
+        }
+    }
+
+    /// Expects the execution of `operations` on `address`
+    pub fn exec(address: u8, operations: &[Operation]) -> Self {
+        Transaction::Exec {
+            address,
+            operations: operations.to_vec(),
+        }
+    }
+}
+
+/// I2C mock
+pub struct Mock {
+    expectations: Expectations<Transaction>,
+}
+
+impl Mock {
+    /// Creates a mock expecting the `expected` transactions, in order
+    pub fn new(expected: &[Transaction]) -> Self {
+        Mock {
+            expectations: Expectations::new(expected),
+        }
+    }
+
+    /// Checks that all the expected transactions were performed
+    pub fn done(&mut self) {
+        self.expectations.done();
+    }
+}
+
+impl Read<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        let actual = Transaction::read(address, &[]);
+        match self.expectations.next(&actual) {
+            Transaction::Read {
+                address: expected,
+                data,
+            } => {
+                assert_eq!(address, expected, "I2C read address");
+                assert_eq!(buffer.len(), data.len(), "I2C read length");
+                buffer.copy_from_slice(&data);
+            }
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+}
+
+impl Write<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        let actual = Transaction::write(address, bytes);
+        match self.expectations.next(&actual) {
+            Transaction::Write {
+                address: expected,
+                data,
+            } => {
+                assert_eq!(address, expected, "I2C write address");
+                assert_eq!(bytes, &data[..], "I2C write data");
+            }
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteIter<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_write_iter<B>(&mut self, address: u8, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        let bytes: Vec<u8> = bytes.into_iter().collect();
+        self.try_write(address, &bytes)
+    }
+}
+
+impl WriteRead<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let actual = Transaction::write_read(address, bytes, &[]);
+        match self.expectations.next(&actual) {
+            Transaction::WriteRead {
+                address: expected,
+                write,
+                read,
+            } => {
+                assert_eq!(address, expected, "I2C write-read address");
+                assert_eq!(bytes, &write[..], "I2C write-read data");
+                assert_eq!(buffer.len(), read.len(), "I2C write-read length");
+                buffer.copy_from_slice(&read);
+            }
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteIterRead<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: u8,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        let bytes: Vec<u8> = bytes.into_iter().collect();
+        self.try_write_read(address, &bytes, buffer)
+    }
+}
+
+impl Transactional<SevenBitAddress> for Mock {
+    type Error = Infallible;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: u8,
+        operations: &mut [i2c::Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        let actual = Transaction::Exec {
+            address,
+            operations: operations
+                .iter()
+                .map(|operation| match operation {
+                    i2c::Operation::Read(_) => Operation::Read(Vec::new()),
+                    i2c::Operation::Write(bytes) => Operation::write(bytes),
+                })
+                .collect(),
+        };
+        match self.expectations.next(&actual) {
+            Transaction::Exec {
+                address: expected,
+                operations: expected_operations,
+            } => {
+                assert_eq!(address, expected, "I2C exec address");
+                assert_eq!(
+                    operations.len(),
+                    expected_operations.len(),
+                    "I2C exec operation count"
+                );
+                for (operation, expected) in operations.iter_mut().zip(expected_operations) {
+                    match (operation, expected) {
+                        (i2c::Operation::Read(buffer), Operation::Read(data)) => {
+                            assert_eq!(buffer.len(), data.len(), "I2C exec read length");
+                            buffer.copy_from_slice(&data);
+                        }
+                        (i2c::Operation::Write(bytes), Operation::Write(data)) => {
+                            assert_eq!(*bytes, &data[..], "I2C exec write data");
+                        }
+                        (_, expected) => {
+                            panic!("expected operation {:?} in {:?}", expected, actual)
+                        }
+                    }
+                }
+            }
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Mock implementations for unit testing drivers on the host
+//!
+//! *This module is available if embedded-hal is built with the `"mock"` feature, which links the
+//! standard library.*
+//!
+//! Each mock is created with the list of transactions the driver under test is expected to
+//! perform, in order. Every call checks the next expected transaction and panics on a mismatch,
+//! which makes the test fail. Call `done` at the end of the test to check that all the expected
+//! transactions were performed.
+//!
+//! Mocks are infallible: their error types are `Infallible`.
+//!
+//! Timers and delays are simulated by the [`time`] module instead, on a clock advanced by the test.
+//!
+//! [`time`]: time/index.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! use embedded_hal::mock::i2c::{Mock, Transaction};
+//!
+//! // driver under test
+//! fn read_id<I: WriteRead>(i2c: &mut I) -> Result<u8, I::Error> {
+//!     let mut id = [0];
+//!     i2c.try_write_read(0x19, &[0x0F], &mut id)?;
+//!     Ok(id[0])
+//! }
+//!
+//! let mut i2c = Mock::new(&[Transaction::write_read(0x19, &[0x0F], &[0x33])]);
+//!
+//! assert_eq!(read_id(&mut i2c).unwrap(), 0x33);
+//!
+//! i2c.done();
+//! ```
+
+pub mod adc;
+pub mod delay;
+pub mod digital;
+pub mod i2c;
+pub mod serial;
+pub mod spi;
+pub mod time;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// Queue of the transactions expected by a mock
+struct Expectations<T> {
+    queue: RefCell<VecDeque<T>>,
+}
+
+impl<T> Expectations<T>
+where
+    T: Clone + Debug,
+{
+    fn new(expected: &[T]) -> Self {
+        Expectations {
+            queue: RefCell::new(expected.iter().cloned().collect()),
+        }
+    }
+
+    /// Pops the next expected transaction
+    ///
+    /// `actual` describes the transaction attempted by the driver, for the panic message.
+    fn next(&self, actual: &dyn Debug) -> T {
+        match self.queue.borrow_mut().pop_front() {
+            Some(expected) => expected,
+            None => panic!("unexpected transaction {:?}: no more were expected", actual),
+        }
+    }
+
+    fn done(&self) {
+        let remaining = self.queue.borrow();
+        assert!(
+            remaining.is_empty(),
+            "expected transactions were not performed: {:?}",
+            *remaining
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Serial mock
+//!
+//! ```
+//! use embedded_hal::blocking::serial::Write;
+//! use embedded_hal::mock::serial::{Mock, Transaction};
+//! use embedded_hal::serial::Read;
+//!
+//! let mut serial = Mock::new(&[
+//!     Transaction::Write(b'A'),
+//!     Transaction::Write(b'T'),
+//!     Transaction::Flush,
+//!     Transaction::Read(b'O'),
+//! ]);
+//!
+//! serial.try_bwrite_all(b"AT").unwrap();
+//! serial.try_bflush().unwrap();
+//! assert_eq!(serial.try_read().unwrap(), b'O');
+//!
+//! serial.done();
+//! ```
+
+use core::convert::Infallible;
+
+use super::Expectations;
+use crate::blocking::serial as blocking;
+use crate::serial::{Read, Write};
+
+/// A serial transaction
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Transaction {
+    /// Read, answered with the given word
+    Read(u8),
+    /// Write of the given word
+    Write(u8),
+    /// Flush
+    Flush,
+}
+
+/// Serial mock
+pub struct Mock {
+    expectations: Expectations<Transaction>,
+}
+
+impl Mock {
+    /// Creates a mock expecting the `expected` transactions, in order
+    pub fn new(expected: &[Transaction]) -> Self {
+        Mock {
+            expectations: Expectations::new(expected),
+        }
+    }
+
+    /// Checks that all the expected transactions were performed
+    pub fn done(&mut self) {
+        self.expectations.done();
+    }
+}
+
+impl Read<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        match self.expectations.next(&"read") {
+            Transaction::Read(word) => Ok(word),
+            expected => panic!("expected {:?}, got a read", expected),
+        }
+    }
+}
+
+impl Write<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        let actual = Transaction::Write(word);
+        match self.expectations.next(&actual) {
+            Transaction::Write(expected) => assert_eq!(word, expected, "serial write data"),
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self.expectations.next(&Transaction::Flush) {
+            Transaction::Flush => Ok(()),
+            expected => panic!("expected {:?}, got {:?}", expected, Transaction::Flush),
+        }
+    }
+}
+
+impl blocking::Write<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_bwrite_all(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        for &word in buffer {
+            block!(self.try_write(word))?;
+        }
+
+        Ok(())
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        block!(self.try_flush())
+    }
+}
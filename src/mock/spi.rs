@@ -0,0 +1,122 @@
+//! SPI mock
+//!
+//! ```
+//! use embedded_hal::blocking::spi::{Transfer, Write};
+//! use embedded_hal::mock::spi::{Mock, Transaction};
+//!
+//! let mut spi = Mock::new(&[
+//!     Transaction::write(&[0x20, 0x47]),
+//!     Transaction::transfer(&[0x8F, 0x00], &[0x00, 0x33]),
+//! ]);
+//!
+//! spi.try_write(&[0x20, 0x47]).unwrap();
+//!
+//! let mut buffer = [0x8F, 0x00];
+//! assert_eq!(spi.try_transfer(&mut buffer).unwrap(), &[0x00, 0x33]);
+//!
+//! spi.done();
+//! ```
+
+use core::convert::Infallible;
+use std::vec::Vec;
+
+use super::Expectations;
+use crate::blocking::spi::{Operation, Transactional, Transfer, Write, WriteIter};
+
+/// An SPI transaction
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Transaction {
+    /// Write of the given words
+    Write(Vec<u8>),
+    /// Transfer of the given words, answered with the second words
+    Transfer(Vec<u8>, Vec<u8>),
+}
+
+impl Transaction {
+    /// Expects a write of `words`
+    pub fn write(words: &[u8]) -> Self {
+        Transaction::Write(words.to_vec())
+    }
+
+    /// Expects a transfer of `words` and answers with `response`
+    pub fn transfer(words: &[u8], response: &[u8]) -> Self {
+        Transaction::Transfer(words.to_vec(), response.to_vec())
+    }
+}
+
+/// SPI mock
+pub struct Mock {
+    expectations: Expectations<Transaction>,
+}
+
+impl Mock {
+    /// Creates a mock expecting the `expected` transactions, in order
+    pub fn new(expected: &[Transaction]) -> Self {
+        Mock {
+            expectations: Expectations::new(expected),
+        }
+    }
+
+    /// Checks that all the expected transactions were performed
+    pub fn done(&mut self) {
+        self.expectations.done();
+    }
+}
+
+impl Write<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        let actual = Transaction::write(words);
+        match self.expectations.next(&actual) {
+            Transaction::Write(expected) => assert_eq!(words, &expected[..], "SPI write data"),
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteIter<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = u8>,
+    {
+        let words: Vec<u8> = words.into_iter().collect();
+        self.try_write(&words)
+    }
+}
+
+impl Transfer<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        let actual = Transaction::Transfer(words.to_vec(), Vec::new());
+        match self.expectations.next(&actual) {
+            Transaction::Transfer(expected, response) => {
+                assert_eq!(&words[..], &expected[..], "SPI transfer data");
+                words.copy_from_slice(&response);
+            }
+            expected => panic!("expected {:?}, got {:?}", expected, actual),
+        }
+
+        Ok(words)
+    }
+}
+
+impl Transactional<u8> for Mock {
+    type Error = Infallible;
+
+    fn try_exec<'a>(&mut self, operations: &mut [Operation<'a, u8>]) -> Result<(), Self::Error> {
+        for operation in operations {
+            match operation {
+                Operation::Write(words) => self.try_write(words)?,
+                Operation::Transfer(words) => self.try_transfer(words).map(|_| ())?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Simulated time
+//!
+//! A [`SimClock`] only moves forward when the test advances it, or when code under test
+//! delays on a [`SimDelay`] created from it. [`SimTimer`]s created from the same clock expire
+//! when the simulated time reaches their deadline. This makes timeouts and debouncing built on
+//! the timer traits testable deterministically, and instantly.
+//!
+//! [`SimClock`]: struct.SimClock.html
+//! [`SimDelay`]: struct.SimDelay.html
+//! [`SimTimer`]: struct.SimTimer.html
+//!
+//! ```
+//! use embedded_hal::mock::time::SimClock;
+//! use embedded_hal::time::U32Ext;
+//! use embedded_hal::timer::CountDown;
+//!
+//! let clock = SimClock::new();
+//! let mut timeout = clock.timer();
+//!
+//! timeout.try_start(10.millis()).unwrap();
+//! assert!(timeout.try_wait().is_err());
+//!
+//! clock.advance(9.millis());
+//! assert!(timeout.try_wait().is_err());
+//!
+//! clock.advance(1.millis());
+//! assert!(timeout.try_wait().is_ok());
+//! ```
+
+use core::cell::Cell;
+use core::convert::Infallible;
+use std::rc::Rc;
+
+use crate::blocking::delay::{DelayMs, DelayNs, DelayUs};
+use crate::time::Duration;
+use crate::timer::{Clock, CountDown};
+
+/// Simulated time source
+///
+/// Clones share the same time. The clock starts at `Duration::default()`, and its instants are
+/// the time elapsed since then.
+#[derive(Clone, Debug, Default)]
+pub struct SimClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl SimClock {
+    /// Creates a clock starting at zero
+    pub fn new() -> Self {
+        SimClock::default()
+    }
+
+    /// Moves the simulated time forward by `duration`
+    pub fn advance<T>(&self, duration: T)
+    where
+        T: Into<Duration>,
+    {
+        self.now.set(self.now.get() + duration.into());
+    }
+
+    /// Returns the simulated time elapsed since the clock was created
+    pub fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    /// Creates a delay that advances this clock instead of waiting
+    pub fn delay(&self) -> SimDelay {
+        SimDelay {
+            clock: self.clone(),
+        }
+    }
+
+    /// Creates a count down timer driven by this clock
+    pub fn timer(&self) -> SimTimer {
+        SimTimer {
+            clock: self.clone(),
+            deadline: None,
+        }
+    }
+}
+
+impl Clock for SimClock {
+    type Error = Infallible;
+    type Time = Duration;
+    type Instant = Duration;
+
+    fn try_now(&self) -> Result<Duration, Self::Error> {
+        Ok(self.now())
+    }
+}
+
+/// Delay advancing a [`SimClock`]
+///
+/// [`SimClock`]: struct.SimClock.html
+///
+/// ```
+/// use embedded_hal::blocking::delay::DelayMs;
+/// use embedded_hal::mock::time::SimClock;
+/// use embedded_hal::time::Duration;
+///
+/// let clock = SimClock::new();
+/// let mut delay = clock.delay();
+///
+/// delay.try_delay_ms(20u32).unwrap();
+///
+/// assert_eq!(clock.now(), Duration::from_millis(20));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SimDelay {
+    clock: SimClock,
+}
+
+impl DelayNs for SimDelay {
+    type Error = Infallible;
+
+    fn try_delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        self.clock.advance(Duration::from_nanos(u64::from(ns)));
+        Ok(())
+    }
+}
+
+impl<UXX> DelayMs<UXX> for SimDelay
+where
+    UXX: Into<u64>,
+{
+    type Error = Infallible;
+
+    fn try_delay_ms(&mut self, ms: UXX) -> Result<(), Self::Error> {
+        self.clock.advance(Duration::from_millis(ms.into()));
+        Ok(())
+    }
+}
+
+impl<UXX> DelayUs<UXX> for SimDelay
+where
+    UXX: Into<u64>,
+{
+    type Error = Infallible;
+
+    fn try_delay_us(&mut self, us: UXX) -> Result<(), Self::Error> {
+        self.clock.advance(Duration::from_micros(us.into()));
+        Ok(())
+    }
+}
+
+/// Count down timer driven by a [`SimClock`]
+///
+/// The timer is one-shot: `try_wait` keeps returning `Ok` once the deadline has passed.
+///
+/// [`SimClock`]: struct.SimClock.html
+#[derive(Clone, Debug)]
+pub struct SimTimer {
+    clock: SimClock,
+    deadline: Option<Duration>,
+}
+
+impl CountDown for SimTimer {
+    type Error = Infallible;
+    type Time = Duration;
+
+    fn try_start<T>(&mut self, count: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>,
+    {
+        self.deadline = Some(self.clock.now() + count.into());
+        Ok(())
+    }
+
+    /// # Panics
+    ///
+    /// If the timer was never started
+    fn try_wait(&mut self) -> nb::Result<(), Self::Error> {
+        let deadline = self.deadline.expect("timer was never started");
+        if self.clock.now() < deadline {
+            Err(nb::Error::WouldBlock)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Mutual exclusion
+//!
+//! Bus sharing proxies such as [`i2c::shared::MutexDevice`], [`adc::shared::SharedAdc`] and
+//! [`pwm::split::PwmChannel`] only need a way to get exclusive access to the shared peripheral
+//! for the duration of an operation. The [`Mutex`] trait abstracts over how that exclusion is
+//! achieved, so the proxies work on bare metal, under RTIC or on top of an RTOS alike: the
+//! environment provides the `Mutex`.
+//!
+//! This crate implements `Mutex` for:
+//!
+//! - `RefCell<T>`, for sharing within a single execution context. Locking panics if the data is
+//!   already locked.
+//! - `critical_section::Mutex<RefCell<T>>`, behind the `critical-section` feature, for sharing
+//!   across execution contexts. The closure runs inside a critical section.
+//!
+//! [`i2c::shared::MutexDevice`]: ../i2c/shared/struct.MutexDevice.html
+//! [`adc::shared::SharedAdc`]: ../adc/shared/struct.SharedAdc.html
+//! [`pwm::split::PwmChannel`]: ../pwm/split/struct.PwmChannel.html
+//! [`Mutex`]: trait.Mutex.html
+//!
+//! # Examples
+//!
+//! An RTOS mutex
+//!
+//! ```
+//! use embedded_hal::mutex::Mutex;
+//! # mod rtos {
+//! #     pub struct Mutex<T>(core::cell::RefCell<T>);
+//! #     impl<T> Mutex<T> {
+//! #         pub fn new(data: T) -> Self { Mutex(core::cell::RefCell::new(data)) }
+//! #         pub fn take(&self) -> core::cell::RefMut<T> { self.0.borrow_mut() }
+//! #     }
+//! # }
+//!
+//! struct OsMutex<T>(rtos::Mutex<T>);
+//!
+//! impl<T> Mutex for OsMutex<T> {
+//!     type Data = T;
+//!
+//!     fn lock<R, F>(&self, f: F) -> R
+//!     where
+//!         F: FnOnce(&mut T) -> R,
+//!     {
+//!         // the guard releases the mutex when dropped
+//!         f(&mut self.0.take())
+//!     }
+//! }
+//!
+//! let counter = OsMutex(rtos::Mutex::new(0));
+//! counter.lock(|count| *count += 1);
+//! assert_eq!(counter.lock(|count| *count), 1);
+//! ```
+
+use core::cell::RefCell;
+
+/// Exclusive access to shared data
+pub trait Mutex {
+    /// Type of the protected data
+    type Data;
+
+    /// Runs `f` with exclusive access to the data
+    ///
+    /// # Contract
+    ///
+    /// - No other `lock` of the same mutex MUST be able to access the data while `f` runs.
+    fn lock<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Self::Data) -> R;
+}
+
+impl<T> Mutex for RefCell<T> {
+    type Data = T;
+
+    /// # Panics
+    ///
+    /// If the data is already locked, e.g. when locking from an interrupt handler while the main
+    /// loop holds the lock, or when locking again from `f`.
+    fn lock<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut self.borrow_mut())
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T> Mutex for critical_section::Mutex<RefCell<T>> {
+    type Data = T;
+
+    /// # Panics
+    ///
+    /// If locking again from `f`
+    fn lock<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        critical_section::with(|cs| f(&mut self.borrow_ref_mut(cs)))
+    }
+}
@@ -0,0 +1,129 @@
+//! Non-blocking traits
+//!
+//! The traits whose methods return [`nb::Result`] are gathered here, grouped by peripheral. They
+//! are the building blocks HALs implement; the blocking traits are derived from them through
+//! wrappers such as `BlockingSpi` or the [`impl_blocking_from_nb!`] macro.
+//!
+//! Drivers that are blocking by nature should be written against the [`blocking`] traits
+//! instead, so their users only deal with plain `Result`s. The traits here are meant for drivers
+//! and applications that schedule the polling themselves, e.g. with [`block!`] or from an event
+//! loop.
+//!
+//! This module only re-exports the traits: they remain defined at their original path, e.g.
+//! `nb::serial::Read` is `serial::Read`. They, and the `nb` crate dependency, are gated behind
+//! the default `nb` feature; drivers written only against the blocking traits can disable default
+//! features to drop them.
+//!
+//! [`nb::Result`]: type.Result.html
+//! [`impl_blocking_from_nb!`]: ../macro.impl_blocking_from_nb.html
+//! [`blocking`]: ../blocking/index.html
+//! [`block!`]: macro.block.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::nb::{self, block, serial::Write};
+//!
+//! fn write_all<S: Write<u8>>(serial: &mut S, bytes: &[u8]) -> Result<(), S::Error> {
+//!     for &byte in bytes {
+//!         block!(serial.try_write(byte))?;
+//!     }
+//!     block!(serial.try_flush())
+//! }
+//!
+//! fn poll_flush<S: Write<u8>>(serial: &mut S) -> nb::Result<(), S::Error> {
+//!     serial.try_flush()
+//! }
+//! ```
+
+pub use ::nb::{block, Error, Result};
+
+/// Non-blocking analog-digital conversion traits
+#[cfg(feature = "adc")]
+pub mod adc {
+    pub use crate::adc::{AnalogWatchdog, Continuous, OneShot, Scan, Triggered};
+}
+
+/// Non-blocking Controller Area Network traits
+#[cfg(feature = "can")]
+pub mod can {
+    pub use crate::can::Mailboxes;
+}
+
+/// Non-blocking input capture traits
+#[cfg(feature = "capture")]
+pub mod capture {
+    pub use crate::capture::{Capture, FrequencyMeter, PwmInput};
+}
+
+/// Non-blocking HDMI Consumer Electronics Control traits
+#[cfg(feature = "cec")]
+pub mod cec {
+    pub use crate::cec::Cec;
+}
+
+/// Non-blocking hall sensor traits
+#[cfg(feature = "hall")]
+pub mod hall {
+    pub use crate::hall::HallSensor;
+}
+
+/// Non-blocking I2C traits
+pub mod i2c {
+    pub use crate::i2c::Target;
+}
+
+/// Non-blocking byte stream traits
+pub mod io {
+    pub use crate::io::{Read, Write};
+}
+
+/// Non-blocking network socket traits
+#[cfg(feature = "net")]
+pub mod net {
+    pub use crate::net::{Dns, TcpClient, UdpClient};
+
+    /// Non-blocking Wi-Fi traits
+    pub mod wifi {
+        pub use crate::net::wifi::{Scan, Station};
+    }
+}
+
+/// Non-blocking PWM traits
+#[cfg(feature = "pwm")]
+pub mod pwm {
+    pub use crate::pwm::OnePulse;
+}
+
+/// Non-blocking random number generation traits
+#[cfg(feature = "rng")]
+pub mod rng {
+    pub use crate::rng::Read;
+}
+
+/// Non-blocking segment LCD traits
+#[cfg(feature = "segment-lcd")]
+pub mod segment_lcd {
+    pub use crate::segment_lcd::SegmentLcd;
+}
+
+/// Non-blocking serial traits
+pub mod serial {
+    pub use crate::serial::{Read, Write};
+}
+
+/// Non-blocking sigma-delta traits
+#[cfg(feature = "sigma-delta")]
+pub mod sigma_delta {
+    pub use crate::sigma_delta::Filter;
+}
+
+/// Non-blocking SPI traits
+pub mod spi {
+    pub use crate::spi::FullDuplex;
+}
+
+/// Non-blocking timer traits
+pub mod timer {
+    pub use crate::timer::{Alarm, CountDown};
+}
@@ -0,0 +1,248 @@
+//! Network sockets
+//!
+//! Traits for TCP and UDP client sockets and name resolution, so network protocol drivers (MQTT,
+//! NTP, HTTP, ...) can run over an AT-command Wi-Fi module, an Ethernet stack or the socket layer
+//! of an OS. The [`wifi`] module manages the Wi-Fi network the stack is connected through.
+//!
+//! A stack hands out sockets, which are then passed back to every operation. Operations that
+//! depend on the network return `nb::Result`s: they return `WouldBlock` until they complete.
+//!
+//! [`wifi`]: wifi/index.html
+//!
+//! # Examples
+//!
+//! Query the time from an NTP server
+//!
+//! ```
+//! #[macro_use(block)]
+//! extern crate nb;
+//! # extern crate embedded_hal;
+//!
+//! use embedded_hal::net::{IpAddr, SocketAddr, UdpClient};
+//!
+//! const NTP_SERVER: SocketAddr = SocketAddr::new(IpAddr::V4([162, 159, 200, 1]), 123);
+//!
+//! /// Returns the number of seconds since 1900-01-01
+//! fn ntp_seconds<S: UdpClient>(stack: &mut S) -> Result<u32, S::Error> {
+//!     let mut socket = stack.try_socket()?;
+//!     stack.try_connect(&mut socket, NTP_SERVER)?;
+//!
+//!     // version 4, client mode
+//!     let mut packet = [0; 48];
+//!     packet[0] = 0x23;
+//!     block!(stack.try_send(&mut socket, &packet))?;
+//!
+//!     let (len, _) = block!(stack.try_receive(&mut socket, &mut packet))?;
+//!     stack.try_close(socket)?;
+//!
+//!     assert!(len >= 44);
+//!     Ok(u32::from_be_bytes([packet[40], packet[41], packet[42], packet[43]]))
+//! }
+//! # fn main() {}
+//! ```
+
+pub mod wifi;
+
+/// An IPv4 or IPv6 address
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IpAddr {
+    /// IPv4 address, most significant octet first
+    V4([u8; 4]),
+    /// IPv6 address, most significant segment first
+    V6([u16; 8]),
+}
+
+/// An IP address and a port number
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SocketAddr {
+    /// IP address
+    pub ip: IpAddr,
+    /// Port number
+    pub port: u16,
+}
+
+impl SocketAddr {
+    /// Creates a socket address from an IP address and a port number
+    pub const fn new(ip: IpAddr, port: u16) -> Self {
+        SocketAddr { ip, port }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<IpAddr> for std::net::IpAddr {
+    fn from(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(octets) => std::net::Ipv4Addr::from(octets).into(),
+            IpAddr::V6(segments) => std::net::Ipv6Addr::from(segments).into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::IpAddr> for IpAddr {
+    fn from(ip: std::net::IpAddr) -> Self {
+        match ip {
+            std::net::IpAddr::V4(ip) => IpAddr::V4(ip.octets()),
+            std::net::IpAddr::V6(ip) => IpAddr::V6(ip.segments()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SocketAddr> for std::net::SocketAddr {
+    fn from(addr: SocketAddr) -> Self {
+        std::net::SocketAddr::new(addr.ip.into(), addr.port)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::SocketAddr> for SocketAddr {
+    fn from(addr: std::net::SocketAddr) -> Self {
+        SocketAddr::new(addr.ip().into(), addr.port())
+    }
+}
+
+/// TCP client
+pub trait TcpClient {
+    /// Handle to a socket of this stack
+    type Socket;
+
+    /// An enumeration of network errors
+    type Error;
+
+    /// Opens a new socket
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the stack has no free socket left.
+    fn try_socket(&mut self) -> Result<Self::Socket, Self::Error>;
+
+    /// Connects `socket` to `remote`
+    ///
+    /// Returns `WouldBlock` until the connection is established.
+    fn try_connect(
+        &mut self,
+        socket: &mut Self::Socket,
+        remote: SocketAddr,
+    ) -> nb::Result<(), Self::Error>;
+
+    /// Returns `true` if `socket` is connected
+    fn try_is_connected(&self, socket: &Self::Socket) -> Result<bool, Self::Error>;
+
+    /// Sends some of the bytes of `buffer` and returns how many were sent
+    ///
+    /// Returns `WouldBlock` if no byte can be sent yet.
+    fn try_send(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &[u8],
+    ) -> nb::Result<usize, Self::Error>;
+
+    /// Receives bytes into `buffer` and returns how many were received
+    ///
+    /// Returns `WouldBlock` if no byte has been received yet, and `Ok(0)` once the remote end
+    /// has closed the connection.
+    fn try_receive(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &mut [u8],
+    ) -> nb::Result<usize, Self::Error>;
+
+    /// Closes `socket`, releasing it
+    fn try_close(&mut self, socket: Self::Socket) -> Result<(), Self::Error>;
+}
+
+/// UDP client
+pub trait UdpClient {
+    /// Handle to a socket of this stack
+    type Socket;
+
+    /// An enumeration of network errors
+    type Error;
+
+    /// Opens a new socket
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the stack has no free socket left.
+    fn try_socket(&mut self) -> Result<Self::Socket, Self::Error>;
+
+    /// Sets the `remote` address datagrams are sent to and received from
+    fn try_connect(
+        &mut self,
+        socket: &mut Self::Socket,
+        remote: SocketAddr,
+    ) -> Result<(), Self::Error>;
+
+    /// Sends `buffer` as a single datagram
+    ///
+    /// Returns `WouldBlock` if the datagram can't be sent yet.
+    fn try_send(&mut self, socket: &mut Self::Socket, buffer: &[u8])
+        -> nb::Result<(), Self::Error>;
+
+    /// Receives a single datagram into `buffer`
+    ///
+    /// Returns the length of the datagram and the address it was sent from, or `WouldBlock` if
+    /// no datagram has been received yet. The end of datagrams longer than `buffer` is
+    /// discarded.
+    fn try_receive(
+        &mut self,
+        socket: &mut Self::Socket,
+        buffer: &mut [u8],
+    ) -> nb::Result<(usize, SocketAddr), Self::Error>;
+
+    /// Closes `socket`, releasing it
+    fn try_close(&mut self, socket: Self::Socket) -> Result<(), Self::Error>;
+}
+
+/// Address family requested from a [`Dns`] resolver
+///
+/// [`Dns`]: trait.Dns.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressFamily {
+    /// IPv4 addresses only
+    V4,
+    /// IPv6 addresses only
+    V6,
+    /// Any address, whichever the resolver prefers
+    Any,
+}
+
+/// Domain name resolver
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::net::{AddressFamily, Dns, SocketAddr};
+///
+/// fn broker<D: Dns>(dns: &mut D) -> nb::Result<SocketAddr, D::Error> {
+///     let ip = dns.try_resolve("broker.example.com", AddressFamily::V4)?;
+///     Ok(SocketAddr::new(ip, 1883))
+/// }
+/// ```
+pub trait Dns {
+    /// An enumeration of resolution errors, e.g. an unknown host
+    type Error;
+
+    /// Resolves `hostname` to an address of `family`
+    ///
+    /// Returns `WouldBlock` until the answer is received.
+    fn try_resolve(
+        &mut self,
+        hostname: &str,
+        family: AddressFamily,
+    ) -> nb::Result<IpAddr, Self::Error>;
+
+    /// Looks up the hostname of `ip`, writing it into `buffer`
+    ///
+    /// Returns the hostname, or `WouldBlock` until the answer is received.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the hostname doesn't fit in `buffer`.
+    fn try_reverse_lookup<'b>(
+        &mut self,
+        ip: IpAddr,
+        buffer: &'b mut [u8],
+    ) -> nb::Result<&'b str, Self::Error>;
+}
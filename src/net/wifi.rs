@@ -0,0 +1,156 @@
+//! Wi-Fi network management
+//!
+//! Traits for scanning, joining and leaving Wi-Fi networks as a station (client), so connectivity
+//! managers can drive AT-command modules and native Wi-Fi SoCs through one interface. Once a
+//! network is joined, the sockets are provided by the [`net`] traits.
+//!
+//! [`net`]: ../index.html
+//!
+//! # Examples
+//!
+//! Join the strongest known network
+//!
+//! ```
+//! #[macro_use(block)]
+//! extern crate nb;
+//! # extern crate embedded_hal;
+//!
+//! use embedded_hal::net::wifi::{Scan, Station};
+//!
+//! const KNOWN: [(&[u8], &[u8]); 2] = [(b"home", b"hunter22"), (b"office", b"c0rp0rate")];
+//!
+//! fn connect<W: Scan + Station<Error = <W as Scan>::Error>>(
+//!     wifi: &mut W,
+//! ) -> Result<bool, <W as Scan>::Error> {
+//!     block!(wifi.try_scan())?;
+//!
+//!     let mut best = None;
+//!     while let Some(network) = wifi.try_next_network()? {
+//!         for &(ssid, passphrase) in KNOWN.iter() {
+//!             if network.ssid() == ssid && best.map_or(true, |(rssi, _, _)| network.rssi > rssi) {
+//!                 best = Some((network.rssi, ssid, passphrase));
+//!             }
+//!         }
+//!     }
+//!
+//!     match best {
+//!         Some((_, ssid, passphrase)) => {
+//!             block!(wifi.try_join(ssid, Some(passphrase)))?;
+//!             Ok(true)
+//!         }
+//!         None => Ok(false),
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+/// Security protocol of a network
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Security {
+    /// No security
+    Open,
+    /// WEP
+    Wep,
+    /// WPA personal (pre-shared key)
+    WpaPersonal,
+    /// WPA2 personal (pre-shared key)
+    Wpa2Personal,
+    /// WPA3 personal (SAE)
+    Wpa3Personal,
+    /// WPA2 / WPA3 enterprise (802.1X)
+    Enterprise,
+}
+
+/// A network found by a scan
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Network {
+    ssid: [u8; 32],
+    ssid_len: u8,
+    /// MAC address of the access point
+    pub bssid: [u8; 6],
+    /// Radio channel
+    pub channel: u8,
+    /// Received signal strength, in dBm
+    pub rssi: i8,
+    /// Security protocol
+    pub security: Security,
+}
+
+impl Network {
+    /// Creates the description of a network
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ssid` is longer than 32 bytes.
+    pub fn new(ssid: &[u8], bssid: [u8; 6], channel: u8, rssi: i8, security: Security) -> Self {
+        let mut network = Network {
+            ssid: [0; 32],
+            ssid_len: ssid.len() as u8,
+            bssid,
+            channel,
+            rssi,
+            security,
+        };
+        network.ssid[..ssid.len()].copy_from_slice(ssid);
+        network
+    }
+
+    /// Returns the SSID of the network
+    ///
+    /// SSIDs are arbitrary bytes, usually but not necessarily UTF-8.
+    pub fn ssid(&self) -> &[u8] {
+        &self.ssid[..usize::from(self.ssid_len)]
+    }
+}
+
+/// State of the link with the access point
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkStatus {
+    /// Not associated with any network
+    Disconnected,
+    /// Joining a network
+    Connecting,
+    /// Associated with a network, and ready to use
+    Connected {
+        /// Received signal strength, in dBm
+        rssi: i8,
+    },
+}
+
+/// Network scan
+pub trait Scan {
+    /// An enumeration of Wi-Fi errors
+    type Error;
+
+    /// Scans for networks
+    ///
+    /// The first call starts a scan; `WouldBlock` is returned until it's over. The networks
+    /// found are then returned by `try_next_network`.
+    fn try_scan(&mut self) -> nb::Result<(), Self::Error>;
+
+    /// Returns the next network found by the last scan, or `None` once all have been returned
+    fn try_next_network(&mut self) -> Result<Option<Network>, Self::Error>;
+}
+
+/// Station (client) mode
+pub trait Station {
+    /// An enumeration of Wi-Fi errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *not found*, the network is out of range
+    /// - *authentication failure*, the passphrase was rejected
+    type Error;
+
+    /// Joins the network `ssid`, using `passphrase` if the network is secured
+    ///
+    /// The first call starts joining the network, leaving the current network if any;
+    /// `WouldBlock` is returned until the link is up.
+    fn try_join(&mut self, ssid: &[u8], passphrase: Option<&[u8]>) -> nb::Result<(), Self::Error>;
+
+    /// Leaves the current network
+    fn try_leave(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the state of the link
+    fn try_link_status(&self) -> Result<LinkStatus, Self::Error>;
+}
@@ -0,0 +1,309 @@
+//! Waker-driven non-blocking traits (experimental)
+//!
+//! The traits in this module are the counterparts of the [`nb`] traits built on
+//! [`core::task::Poll`]. Instead of returning `WouldBlock` and expecting to be polled again, an
+//! implementation that is not ready registers the [`Waker`] of the `Context` (e.g. in its
+//! interrupt handler) and wakes it once progress can be made. This lets async executors sleep
+//! until the peripheral is ready instead of busy polling.
+//!
+//! Each module also provides functions turning a single operation into a [`Future`].
+//!
+//! This module requires the `async` feature. It is experimental and may change in any release.
+//!
+//! [`nb`]: ../nb/index.html
+//! [`core::task::Poll`]: https://doc.rust-lang.org/core/task/enum.Poll.html
+//! [`Waker`]: https://doc.rust-lang.org/core/task/struct.Waker.html
+//! [`Future`]: https://doc.rust-lang.org/core/future/trait.Future.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::poll::serial::{self, Read, Write};
+//! use embedded_hal::poll::spi::{self, FullDuplex};
+//!
+//! async fn echo<S>(serial: &mut S) -> Result<(), <S as Read<u8>>::Error>
+//! where
+//!     S: Read<u8> + Write<u8, Error = <S as Read<u8>>::Error>,
+//! {
+//!     loop {
+//!         let byte = serial::read(serial).await?;
+//!         serial::write(serial, byte).await?;
+//!     }
+//! }
+//!
+//! async fn transfer<S: FullDuplex<u8>>(spi: &mut S, words: &mut [u8]) -> Result<(), S::Error> {
+//!     for word in words {
+//!         spi::send(spi, *word).await?;
+//!         *word = spi::read(spi).await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+/// Serial interface
+pub mod serial {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    /// Read half of a serial interface
+    pub trait Read<Word> {
+        /// Read error
+        type Error;
+
+        /// Reads a single word from the serial interface
+        ///
+        /// Returns `Poll::Pending` if no word has been received yet, in which case the waker of
+        /// `cx` is woken once a word is available.
+        fn poll_read(&mut self, cx: &mut Context<'_>) -> Poll<Result<Word, Self::Error>>;
+    }
+
+    /// Write half of a serial interface
+    pub trait Write<Word> {
+        /// Write error
+        type Error;
+
+        /// Writes a single word to the serial interface
+        ///
+        /// Returns `Poll::Pending` if the interface can't accept the word yet, in which case the
+        /// waker of `cx` is woken once it can. A pending word must be written again on the next
+        /// call.
+        fn poll_write(&mut self, cx: &mut Context<'_>, word: Word)
+            -> Poll<Result<(), Self::Error>>;
+
+        /// Ensures that none of the previously written words are still buffered
+        fn poll_flush(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+    }
+
+    /// Future returned by [`read`](fn.read.html)
+    pub struct ReadFuture<'a, S: ?Sized, Word> {
+        serial: &'a mut S,
+        _word: core::marker::PhantomData<Word>,
+    }
+
+    /// Reads a single word from `serial`
+    pub fn read<S, Word>(serial: &mut S) -> ReadFuture<'_, S, Word>
+    where
+        S: Read<Word> + ?Sized,
+    {
+        ReadFuture {
+            serial,
+            _word: core::marker::PhantomData,
+        }
+    }
+
+    impl<'a, S, Word> Future for ReadFuture<'a, S, Word>
+    where
+        S: Read<Word> + ?Sized,
+    {
+        type Output = Result<Word, S::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.get_mut().serial.poll_read(cx)
+        }
+    }
+
+    // the futures are never pinned structurally
+    impl<'a, S: ?Sized, Word> Unpin for ReadFuture<'a, S, Word> {}
+
+    /// Future returned by [`write`](fn.write.html)
+    pub struct WriteFuture<'a, S: ?Sized, Word> {
+        serial: &'a mut S,
+        word: Word,
+    }
+
+    /// Writes a single word to `serial`
+    pub fn write<S, Word>(serial: &mut S, word: Word) -> WriteFuture<'_, S, Word>
+    where
+        S: Write<Word> + ?Sized,
+        Word: Clone,
+    {
+        WriteFuture { serial, word }
+    }
+
+    impl<'a, S, Word> Future for WriteFuture<'a, S, Word>
+    where
+        S: Write<Word> + ?Sized,
+        Word: Clone,
+    {
+        type Output = Result<(), S::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            this.serial.poll_write(cx, this.word.clone())
+        }
+    }
+
+    // the futures are never pinned structurally
+    impl<'a, S: ?Sized, Word> Unpin for WriteFuture<'a, S, Word> {}
+
+    /// Future returned by [`flush`](fn.flush.html)
+    pub struct FlushFuture<'a, S: ?Sized, Word> {
+        serial: &'a mut S,
+        _word: core::marker::PhantomData<Word>,
+    }
+
+    /// Waits until none of the words previously written to `serial` are still buffered
+    pub fn flush<S, Word>(serial: &mut S) -> FlushFuture<'_, S, Word>
+    where
+        S: Write<Word> + ?Sized,
+    {
+        FlushFuture {
+            serial,
+            _word: core::marker::PhantomData,
+        }
+    }
+
+    impl<'a, S, Word> Future for FlushFuture<'a, S, Word>
+    where
+        S: Write<Word> + ?Sized,
+    {
+        type Output = Result<(), S::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.get_mut().serial.poll_flush(cx)
+        }
+    }
+
+    // the futures are never pinned structurally
+    impl<'a, S: ?Sized, Word> Unpin for FlushFuture<'a, S, Word> {}
+}
+
+/// Serial Peripheral Interface
+pub mod spi {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    /// Full duplex (master mode)
+    ///
+    /// The same rules as for [`spi::FullDuplex`] apply: each `poll_send` must be followed by a
+    /// `poll_read` before the next word is sent.
+    ///
+    /// [`spi::FullDuplex`]: ../../spi/trait.FullDuplex.html
+    pub trait FullDuplex<Word> {
+        /// An enumeration of SPI errors
+        type Error;
+
+        /// Reads the word stored in the shift register
+        fn poll_read(&mut self, cx: &mut Context<'_>) -> Poll<Result<Word, Self::Error>>;
+
+        /// Sends a word to the slave
+        fn poll_send(&mut self, cx: &mut Context<'_>, word: Word) -> Poll<Result<(), Self::Error>>;
+    }
+
+    /// Future returned by [`read`](fn.read.html)
+    pub struct ReadFuture<'a, S: ?Sized, Word> {
+        spi: &'a mut S,
+        _word: core::marker::PhantomData<Word>,
+    }
+
+    /// Reads the word received during the last send of `spi`
+    pub fn read<S, Word>(spi: &mut S) -> ReadFuture<'_, S, Word>
+    where
+        S: FullDuplex<Word> + ?Sized,
+    {
+        ReadFuture {
+            spi,
+            _word: core::marker::PhantomData,
+        }
+    }
+
+    impl<'a, S, Word> Future for ReadFuture<'a, S, Word>
+    where
+        S: FullDuplex<Word> + ?Sized,
+    {
+        type Output = Result<Word, S::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.get_mut().spi.poll_read(cx)
+        }
+    }
+
+    // the futures are never pinned structurally
+    impl<'a, S: ?Sized, Word> Unpin for ReadFuture<'a, S, Word> {}
+
+    /// Future returned by [`send`](fn.send.html)
+    pub struct SendFuture<'a, S: ?Sized, Word> {
+        spi: &'a mut S,
+        word: Word,
+    }
+
+    /// Sends a single word to the slave through `spi`
+    pub fn send<S, Word>(spi: &mut S, word: Word) -> SendFuture<'_, S, Word>
+    where
+        S: FullDuplex<Word> + ?Sized,
+        Word: Clone,
+    {
+        SendFuture { spi, word }
+    }
+
+    impl<'a, S, Word> Future for SendFuture<'a, S, Word>
+    where
+        S: FullDuplex<Word> + ?Sized,
+        Word: Clone,
+    {
+        type Output = Result<(), S::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            this.spi.poll_send(cx, this.word.clone())
+        }
+    }
+
+    // the futures are never pinned structurally
+    impl<'a, S: ?Sized, Word> Unpin for SendFuture<'a, S, Word> {}
+}
+
+/// Timers
+pub mod timer {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    /// A count down timer
+    pub trait CountDown {
+        /// An enumeration of `CountDown` errors.
+        ///
+        /// For infallible implementations, will be `Infallible`
+        type Error;
+
+        /// The unit of time used by this timer
+        type Time;
+
+        /// Starts a new count down
+        fn try_start<T>(&mut self, count: T) -> Result<(), Self::Error>
+        where
+            T: Into<Self::Time>;
+
+        /// Completes once the count down is over
+        ///
+        /// Returns `Poll::Pending` while the count down is running, in which case the waker of `cx`
+        /// is woken once it's over.
+        fn poll_wait(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+    }
+
+    /// Future returned by [`wait`](fn.wait.html)
+    pub struct WaitFuture<'a, T: ?Sized> {
+        timer: &'a mut T,
+    }
+
+    /// Waits until the count down of `timer` is over
+    pub fn wait<T>(timer: &mut T) -> WaitFuture<'_, T>
+    where
+        T: CountDown + ?Sized,
+    {
+        WaitFuture { timer }
+    }
+
+    impl<'a, T> Future for WaitFuture<'a, T>
+    where
+        T: CountDown + ?Sized,
+    {
+        type Output = Result<(), T::Error>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            self.get_mut().timer.poll_wait(cx)
+        }
+    }
+}
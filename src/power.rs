@@ -0,0 +1,261 @@
+//! Power management
+//!
+//! - [`WakeupSource`] configures the peripherals able to wake the device up from a low-power
+//!   mode.
+//! - [`BrownOut`] configures the supply voltage supervisor.
+//! - [`FrequencyScaling`] switches the core between performance levels at runtime, notifying
+//!   the drivers that depend on its frequency through [`FrequencyListener`].
+//!
+//! [`WakeupSource`]: trait.WakeupSource.html
+//! [`BrownOut`]: trait.BrownOut.html
+//! [`FrequencyScaling`]: trait.FrequencyScaling.html
+//! [`FrequencyListener`]: trait.FrequencyListener.html
+
+use crate::time::Hertz;
+
+/// Source of wakeup from a low-power mode
+///
+/// Implemented by the peripherals and pins able to wake the device up, e.g. an input pin, the RTC
+/// alarm or a low-power UART. Each driver configures the wakeup capability of the peripheral it
+/// owns, and sleep managers check the sources before entering a low-power mode.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::power::WakeupSource;
+///
+/// /// Returns `false` if entering the low-power mode would never wake the device up
+/// fn can_sleep<E>(sources: &[&dyn WakeupSource<Error = E>]) -> Result<bool, E> {
+///     for source in sources {
+///         if source.try_is_wakeup_enabled()? {
+///             return Ok(true);
+///         }
+///     }
+///
+///     Ok(false)
+/// }
+///
+/// let mut button: Pa0 = {
+///     // ..
+/// #   Pa0(false)
+/// };
+/// let alarm: RtcAlarm = {
+///     // ..
+/// #   RtcAlarm(false)
+/// };
+///
+/// assert!(!can_sleep(&[&button, &alarm]).unwrap());
+///
+/// button.try_enable_wakeup().unwrap();
+/// assert!(can_sleep(&[&button, &alarm]).unwrap());
+///
+/// # use core::convert::Infallible;
+/// # struct Pa0(bool);
+/// # struct RtcAlarm(bool);
+/// # impl WakeupSource for Pa0 {
+/// #     type Error = Infallible;
+/// #     fn try_enable_wakeup(&mut self) -> Result<(), Infallible> { self.0 = true; Ok(()) }
+/// #     fn try_disable_wakeup(&mut self) -> Result<(), Infallible> { self.0 = false; Ok(()) }
+/// #     fn try_is_wakeup_enabled(&self) -> Result<bool, Infallible> { Ok(self.0) }
+/// #     fn try_caused_wakeup(&self) -> Result<bool, Infallible> { Ok(false) }
+/// # }
+/// # impl WakeupSource for RtcAlarm {
+/// #     type Error = Infallible;
+/// #     fn try_enable_wakeup(&mut self) -> Result<(), Infallible> { self.0 = true; Ok(()) }
+/// #     fn try_disable_wakeup(&mut self) -> Result<(), Infallible> { self.0 = false; Ok(()) }
+/// #     fn try_is_wakeup_enabled(&self) -> Result<bool, Infallible> { Ok(self.0) }
+/// #     fn try_caused_wakeup(&self) -> Result<bool, Infallible> { Ok(false) }
+/// # }
+/// ```
+pub trait WakeupSource {
+    /// An enumeration of wakeup errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *unsupported*, the source can't wake the device up in its current configuration, e.g. a
+    ///   UART clocked by a clock that is stopped in low-power modes
+    type Error;
+
+    /// Allows the source to wake the device up
+    fn try_enable_wakeup(&mut self) -> Result<(), Self::Error>;
+
+    /// Prevents the source from waking the device up
+    fn try_disable_wakeup(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if the source can wake the device up
+    fn try_is_wakeup_enabled(&self) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the source woke the device up from the last low-power mode
+    fn try_caused_wakeup(&self) -> Result<bool, Self::Error>;
+}
+
+/// Brown-out detector
+///
+/// The brown-out detector resets the device, or raises an interrupt, when the supply voltage
+/// drops below a threshold, before the device starts misbehaving.
+///
+/// # Examples
+///
+/// Checking the supply at boot
+///
+/// ```
+/// use embedded_hal::power::BrownOut;
+///
+/// fn check_supply<B: BrownOut>(bod: &mut B) -> Result<(), B::Error> {
+///     if bod.try_brown_out_occurred()? {
+///         // the battery is probably getting empty
+///         println!("brown-out below {} mV", bod.try_threshold()?);
+///     }
+///
+///     // the flash needs 2.7 V to be written reliably
+///     let threshold = bod.try_set_threshold(2_700)?;
+///     assert!(threshold >= 2_700);
+///     Ok(())
+/// }
+///
+/// let mut bod: Pvd = {
+///     // ..
+/// #   Pvd(2_000)
+/// };
+///
+/// check_supply(&mut bod).unwrap();
+///
+/// # use core::convert::Infallible;
+/// # struct Pvd(u16);
+/// # impl BrownOut for Pvd {
+/// #     type Error = Infallible;
+/// #     fn try_threshold(&self) -> Result<u16, Infallible> { Ok(self.0) }
+/// #     fn try_set_threshold(&mut self, _: u16) -> Result<u16, Infallible> { self.0 = 2_800; Ok(2_800) }
+/// #     fn try_brown_out_occurred(&mut self) -> Result<bool, Infallible> { Ok(true) }
+/// # }
+/// ```
+pub trait BrownOut {
+    /// An enumeration of brown-out detector errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of range*, the threshold is above the highest supported level
+    /// - *locked*, the threshold is set by option bytes or was locked until the next reset
+    type Error;
+
+    /// Returns the threshold, in millivolts
+    fn try_threshold(&self) -> Result<u16, Self::Error>;
+
+    /// Sets the threshold to `millivolts`
+    ///
+    /// The threshold is rounded up to the next supported level, so the detector trips no later
+    /// than requested. Returns the threshold actually set, in millivolts.
+    fn try_set_threshold(&mut self, millivolts: u16) -> Result<u16, Self::Error>;
+
+    /// Returns `true` if a brown-out occurred since the last call, and clears the event
+    ///
+    /// For detectors that reset the device, this is the case if the last reset was caused by a
+    /// brown-out.
+    fn try_brown_out_occurred(&mut self) -> Result<bool, Self::Error>;
+}
+
+/// Dynamic frequency (and voltage) scaling of the core
+///
+/// The performance levels are defined by the implementation, e.g. the operating points of the
+/// PLL and the voltage regulator. Changing the core frequency usually changes the bus clocks too,
+/// so the drivers deriving baud rates or timings from them are given a chance to recalibrate.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::power::{FrequencyListener, FrequencyScaling};
+/// use embedded_hal::time::{Hertz, U32Ext};
+///
+/// struct Uart {
+///     baud: u32,
+///     divider: u32,
+/// }
+///
+/// impl FrequencyListener for Uart {
+///     fn frequency_changed(&mut self, core: Hertz) {
+///         self.divider = core.0 / self.baud;
+///     }
+/// }
+///
+/// let mut cpu: Cpu = {
+///     // ..
+/// #   Cpu(Level::Low)
+/// };
+/// let mut uart = Uart { baud: 115_200, divider: 139 };
+///
+/// cpu.try_set_level(Level::High, &mut [&mut uart]).unwrap();
+/// assert_eq!(uart.divider, 625);
+///
+/// # use core::convert::Infallible;
+/// # #[derive(Clone, Copy)]
+/// # enum Level { Low, High }
+/// # struct Cpu(Level);
+/// # impl FrequencyScaling for Cpu {
+/// #     type Error = Infallible;
+/// #     type Level = Level;
+/// #     fn try_level(&self) -> Result<Level, Infallible> { Ok(self.0) }
+/// #     fn try_frequency(&self) -> Result<Hertz, Infallible> {
+/// #         Ok(match self.0 { Level::Low => 16_000_000.hz(), Level::High => 72_000_000.hz() })
+/// #     }
+/// #     fn try_set_level(
+/// #         &mut self,
+/// #         level: Level,
+/// #         listeners: &mut [&mut dyn FrequencyListener],
+/// #     ) -> Result<(), Infallible> {
+/// #         let from = self.try_frequency()?;
+/// #         let to = Cpu(level).try_frequency()?;
+/// #         for listener in listeners.iter_mut() {
+/// #             listener.frequency_changing(from, to);
+/// #         }
+/// #         self.0 = level;
+/// #         for listener in listeners.iter_mut() {
+/// #             listener.frequency_changed(to);
+/// #         }
+/// #         Ok(())
+/// #     }
+/// # }
+/// ```
+pub trait FrequencyScaling {
+    /// An enumeration of frequency scaling errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *timeout*, the PLL didn't lock or the regulator didn't reach the voltage in time
+    type Error;
+
+    /// Enumeration of the performance levels
+    type Level;
+
+    /// Returns the current performance level
+    fn try_level(&self) -> Result<Self::Level, Self::Error>;
+
+    /// Returns the current core frequency
+    fn try_frequency(&self) -> Result<Hertz, Self::Error>;
+
+    /// Switches to `level`
+    ///
+    /// `listeners` are notified before and after the switch.
+    fn try_set_level(
+        &mut self,
+        level: Self::Level,
+        listeners: &mut [&mut dyn FrequencyListener],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Notification of core frequency changes
+///
+/// Implemented by drivers whose timings depend on the core or bus clocks.
+///
+/// # Contract
+///
+/// - `FrequencyScaling` implementations MUST call `frequency_changing` before and
+///   `frequency_changed` after every switch, on every listener.
+pub trait FrequencyListener {
+    /// Called before the frequency changes from `from` to `to`, e.g. to finish a transfer
+    fn frequency_changing(&mut self, from: Hertz, to: Hertz) {
+        let _ = (from, to);
+    }
+
+    /// Called once the core runs at `core`
+    fn frequency_changed(&mut self, core: Hertz);
+}
@@ -1,32 +1,57 @@
-//! The prelude is a collection of all the traits in this crate
+//! The prelude is a collection of the most used traits of this crate
 //!
 //! The traits have been renamed to avoid collisions with other items when
 //! performing a glob import.
+//!
+//! Every trait of a domain, including the configuration and extension traits
+//! left out of the root prelude, is exported, renamed the same way, by the
+//! prelude of that domain in [`preludes`], e.g. `preludes::timer`.
+//!
+//! [`preludes`]: ../preludes/index.html
+//!
+//! ```
+//! use embedded_hal::prelude::*;
+//! use embedded_hal::preludes::timer::*;
+//! ```
 
+#[cfg(feature = "adc")]
 pub use crate::adc::OneShot as _embedded_hal_adc_OneShot;
 pub use crate::blocking::delay::DelayMs as _embedded_hal_blocking_delay_DelayMs;
+pub use crate::blocking::delay::DelayNs as _embedded_hal_blocking_delay_DelayNs;
 pub use crate::blocking::delay::DelayUs as _embedded_hal_blocking_delay_DelayUs;
-pub use crate::blocking::i2c::{
-    Read as _embedded_hal_blocking_i2c_Read, Write as _embedded_hal_blocking_i2c_Write,
-    WriteRead as _embedded_hal_blocking_i2c_WriteRead,
-};
+pub use crate::blocking::i2c::Read as _embedded_hal_blocking_i2c_Read;
+pub use crate::blocking::i2c::Write as _embedded_hal_blocking_i2c_Write;
+pub use crate::blocking::i2c::WriteRead as _embedded_hal_blocking_i2c_WriteRead;
+#[cfg(feature = "rng")]
 pub use crate::blocking::rng::Read as _embedded_hal_blocking_rng_Read;
 pub use crate::blocking::serial::Write as _embedded_hal_blocking_serial_Write;
-pub use crate::blocking::spi::{
-    Transfer as _embedded_hal_blocking_spi_Transfer, Write as _embedded_hal_blocking_spi_Write,
-};
+pub use crate::blocking::spi::Transfer as _embedded_hal_blocking_spi_Transfer;
+pub use crate::blocking::spi::Write as _embedded_hal_blocking_spi_Write;
+#[cfg(feature = "capture")]
 pub use crate::capture::Capture as _embedded_hal_Capture;
 pub use crate::digital::InputPin as _embedded_hal_digital_InputPin;
 pub use crate::digital::OutputPin as _embedded_hal_digital_OutputPin;
 pub use crate::digital::ToggleableOutputPin as _embedded_hal_digital_ToggleableOutputPin;
+#[cfg(feature = "pwm")]
 pub use crate::pwm::Pwm as _embedded_hal_Pwm;
+#[cfg(feature = "pwm")]
 pub use crate::pwm::PwmPin as _embedded_hal_PwmPin;
+#[cfg(feature = "qei")]
 pub use crate::qei::Qei as _embedded_hal_Qei;
+#[cfg(feature = "rng")]
 pub use crate::rng::Read as _embedded_hal_rng_Read;
+#[cfg(feature = "nb")]
 pub use crate::serial::Read as _embedded_hal_serial_Read;
+#[cfg(feature = "nb")]
 pub use crate::serial::Write as _embedded_hal_serial_Write;
+#[cfg(feature = "nb")]
 pub use crate::spi::FullDuplex as _embedded_hal_spi_FullDuplex;
+pub use crate::time::U32Ext as _embedded_hal_time_U32Ext;
+#[cfg(feature = "nb")]
 pub use crate::timer::CountDown as _embedded_hal_timer_CountDown;
+#[cfg(feature = "watchdog")]
 pub use crate::watchdog::Watchdog as _embedded_hal_watchdog_Watchdog;
+#[cfg(feature = "watchdog")]
 pub use crate::watchdog::WatchdogDisable as _embedded_hal_watchdog_WatchdogDisable;
+#[cfg(feature = "watchdog")]
 pub use crate::watchdog::WatchdogEnable as _embedded_hal_watchdog_WatchdogEnable;
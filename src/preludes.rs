@@ -0,0 +1,302 @@
+//! Per-domain preludes
+//!
+//! Each module exports every trait of its domain, including the configuration and extension
+//! traits left out of the root [`prelude`], under the same `_embedded_hal_*` aliases. They live
+//! outside of the root prelude so that glob importing it doesn't bring their names, e.g. `spi`,
+//! into scope, where they would clash with the modules of this crate.
+//!
+//! [`prelude`]: ../prelude/index.html
+//!
+//! ```
+//! use embedded_hal::prelude::*;
+//! use embedded_hal::preludes::timer::*;
+//! use embedded_hal::*;
+//! ```
+
+/// Analog-digital conversion traits
+#[cfg(feature = "adc")]
+pub mod adc {
+    pub use crate::adc::AnalogWatchdog as _embedded_hal_adc_AnalogWatchdog;
+    pub use crate::adc::Continuous as _embedded_hal_adc_Continuous;
+    pub use crate::adc::OneShot as _embedded_hal_adc_OneShot;
+    pub use crate::adc::ReadDma as _embedded_hal_adc_ReadDma;
+    pub use crate::adc::ReferenceVoltage as _embedded_hal_adc_ReferenceVoltage;
+    pub use crate::adc::Resolution as _embedded_hal_adc_Resolution;
+    pub use crate::adc::Scan as _embedded_hal_adc_Scan;
+    pub use crate::adc::SetOversampling as _embedded_hal_adc_SetOversampling;
+    pub use crate::adc::SetResolution as _embedded_hal_adc_SetResolution;
+    pub use crate::adc::SetSampleTime as _embedded_hal_adc_SetSampleTime;
+    pub use crate::adc::Triggered as _embedded_hal_adc_Triggered;
+}
+
+/// Controller Area Network traits
+#[cfg(feature = "can")]
+pub mod can {
+    pub use crate::can::Configure as _embedded_hal_can_Configure;
+    pub use crate::can::Mailboxes as _embedded_hal_can_Mailboxes;
+}
+
+/// Input capture traits
+#[cfg(feature = "capture")]
+pub mod capture {
+    pub use crate::capture::Capture as _embedded_hal_Capture;
+    pub use crate::capture::CaptureDma as _embedded_hal_capture_CaptureDma;
+    pub use crate::capture::FrequencyMeter as _embedded_hal_capture_FrequencyMeter;
+    pub use crate::capture::Overcapture as _embedded_hal_capture_Overcapture;
+    pub use crate::capture::PwmInput as _embedded_hal_capture_PwmInput;
+    pub use crate::capture::SetEdge as _embedded_hal_capture_SetEdge;
+}
+
+/// HDMI-CEC traits
+#[cfg(feature = "cec")]
+pub mod cec {
+    pub use crate::cec::Cec as _embedded_hal_cec_Cec;
+}
+
+/// Clock management traits
+#[cfg(feature = "clock")]
+pub mod clock {
+    pub use crate::clock::ClockOutput as _embedded_hal_clock_ClockOutput;
+}
+
+/// Delay traits
+pub mod delay {
+    pub use crate::blocking::delay::DelayMs as _embedded_hal_blocking_delay_DelayMs;
+    pub use crate::blocking::delay::DelayNs as _embedded_hal_blocking_delay_DelayNs;
+    pub use crate::blocking::delay::DelayUs as _embedded_hal_blocking_delay_DelayUs;
+}
+
+/// Digital I/O traits
+pub mod digital {
+    pub use crate::digital::InputPin as _embedded_hal_digital_InputPin;
+    pub use crate::digital::OutputPin as _embedded_hal_digital_OutputPin;
+    pub use crate::digital::StatefulOutputPin as _embedded_hal_digital_StatefulOutputPin;
+    pub use crate::digital::ToggleableOutputPin as _embedded_hal_digital_ToggleableOutputPin;
+}
+
+/// Direct memory access traits
+pub mod dma {
+    pub use crate::dma::Transfer as _embedded_hal_dma_Transfer;
+}
+
+/// Hall sensor traits
+#[cfg(feature = "hall")]
+pub mod hall {
+    pub use crate::hall::HallSensor as _embedded_hal_hall_HallSensor;
+}
+
+/// I2C traits
+pub mod i2c {
+    pub use crate::blocking::i2c::I2c as _embedded_hal_blocking_i2c_I2c;
+    pub use crate::blocking::i2c::Read as _embedded_hal_blocking_i2c_Read;
+    pub use crate::blocking::i2c::RegisterRead as _embedded_hal_blocking_i2c_RegisterRead;
+    pub use crate::blocking::i2c::RegisterWrite as _embedded_hal_blocking_i2c_RegisterWrite;
+    pub use crate::blocking::i2c::Transactional as _embedded_hal_blocking_i2c_Transactional;
+    pub use crate::blocking::i2c::Write as _embedded_hal_blocking_i2c_Write;
+    pub use crate::blocking::i2c::WriteIter as _embedded_hal_blocking_i2c_WriteIter;
+    pub use crate::blocking::i2c::WriteIterRead as _embedded_hal_blocking_i2c_WriteIterRead;
+    pub use crate::blocking::i2c::WriteRead as _embedded_hal_blocking_i2c_WriteRead;
+    pub use crate::i2c::ReadDma as _embedded_hal_i2c_ReadDma;
+    pub use crate::i2c::SetSpeed as _embedded_hal_i2c_SetSpeed;
+    #[cfg(feature = "nb")]
+    pub use crate::i2c::Target as _embedded_hal_i2c_Target;
+    pub use crate::i2c::WriteDma as _embedded_hal_i2c_WriteDma;
+}
+
+/// Byte stream traits
+#[cfg(feature = "nb")]
+pub mod io {
+    pub use crate::io::Read as _embedded_hal_io_Read;
+    pub use crate::io::Write as _embedded_hal_io_Write;
+}
+
+/// External memory bus traits
+#[cfg(feature = "memory-bus")]
+pub mod memory_bus {
+    pub use crate::memory_bus::MemoryBus as _embedded_hal_memory_bus_MemoryBus;
+}
+
+/// Mutual exclusion traits
+pub mod mutex {
+    pub use crate::mutex::Mutex as _embedded_hal_mutex_Mutex;
+}
+
+/// Network socket traits
+#[cfg(feature = "net")]
+pub mod net {
+    pub use crate::net::wifi::Scan as _embedded_hal_net_wifi_Scan;
+    pub use crate::net::wifi::Station as _embedded_hal_net_wifi_Station;
+    pub use crate::net::Dns as _embedded_hal_net_Dns;
+    pub use crate::net::TcpClient as _embedded_hal_net_TcpClient;
+    pub use crate::net::UdpClient as _embedded_hal_net_UdpClient;
+}
+
+/// Power management traits
+#[cfg(feature = "power")]
+pub mod power {
+    pub use crate::power::BrownOut as _embedded_hal_power_BrownOut;
+    pub use crate::power::FrequencyListener as _embedded_hal_power_FrequencyListener;
+    pub use crate::power::FrequencyScaling as _embedded_hal_power_FrequencyScaling;
+    pub use crate::power::WakeupSource as _embedded_hal_power_WakeupSource;
+}
+
+/// Debug probe traits
+#[cfg(feature = "probe")]
+pub mod probe {
+    pub use crate::probe::Jtag as _embedded_hal_probe_Jtag;
+    pub use crate::probe::Swd as _embedded_hal_probe_Swd;
+}
+
+/// Pulse width modulation traits
+#[cfg(feature = "pwm")]
+pub mod pwm {
+    pub use crate::pwm::Break as _embedded_hal_pwm_Break;
+    pub use crate::pwm::Complementary as _embedded_hal_pwm_Complementary;
+    #[cfg(feature = "nb")]
+    pub use crate::pwm::OnePulse as _embedded_hal_pwm_OnePulse;
+    pub use crate::pwm::Pwm as _embedded_hal_Pwm;
+    pub use crate::pwm::PwmPin as _embedded_hal_PwmPin;
+    pub use crate::pwm::SetAlignment as _embedded_hal_pwm_SetAlignment;
+    pub use crate::pwm::SetFrequency as _embedded_hal_pwm_SetFrequency;
+    pub use crate::pwm::SetPhase as _embedded_hal_pwm_SetPhase;
+    pub use crate::pwm::Tone as _embedded_hal_pwm_Tone;
+}
+
+/// Quadrature encoder interface traits
+#[cfg(feature = "qei")]
+pub mod qei {
+    pub use crate::qei::Configure as _embedded_hal_qei_Configure;
+    pub use crate::qei::Qei as _embedded_hal_Qei;
+    pub use crate::qei::SetCount as _embedded_hal_qei_SetCount;
+}
+
+/// Random number generation traits
+#[cfg(feature = "rng")]
+pub mod rng {
+    pub use crate::blocking::rng::Read as _embedded_hal_blocking_rng_Read;
+    pub use crate::rng::Read as _embedded_hal_rng_Read;
+}
+
+/// Real-time clock traits
+#[cfg(feature = "rtc")]
+pub mod rtc {
+    pub use crate::rtc::Calibrate as _embedded_hal_rtc_Calibrate;
+}
+
+/// Segment LCD traits
+#[cfg(feature = "segment-lcd")]
+pub mod segment_lcd {
+    pub use crate::segment_lcd::Contrast as _embedded_hal_segment_lcd_Contrast;
+    pub use crate::segment_lcd::SegmentLcd as _embedded_hal_segment_lcd_SegmentLcd;
+}
+
+/// Serial interface traits
+pub mod serial {
+    pub use crate::blocking::serial::Write as _embedded_hal_blocking_serial_Write;
+    #[cfg(feature = "nb")]
+    pub use crate::serial::Read as _embedded_hal_serial_Read;
+    #[cfg(feature = "nb")]
+    pub use crate::serial::Write as _embedded_hal_serial_Write;
+}
+
+/// Sigma-delta modulator traits
+#[cfg(feature = "sigma-delta")]
+pub mod sigma_delta {
+    pub use crate::sigma_delta::Filter as _embedded_hal_sigma_delta_Filter;
+}
+
+/// Serial peripheral interface traits
+pub mod spi {
+    pub use crate::blocking::spi::RegisterRead as _embedded_hal_blocking_spi_RegisterRead;
+    pub use crate::blocking::spi::RegisterWrite as _embedded_hal_blocking_spi_RegisterWrite;
+    pub use crate::blocking::spi::Transactional as _embedded_hal_blocking_spi_Transactional;
+    pub use crate::blocking::spi::Transfer as _embedded_hal_blocking_spi_Transfer;
+    pub use crate::blocking::spi::Write as _embedded_hal_blocking_spi_Write;
+    #[cfg(feature = "nb")]
+    pub use crate::spi::FullDuplex as _embedded_hal_spi_FullDuplex;
+}
+
+/// Non-volatile storage traits
+///
+/// `Eeprom` isn't exported: every EEPROM implements `ReadStorage` and `Storage`, whose methods
+/// have the same names, which would make calls ambiguous.
+///
+/// ```
+/// use embedded_hal::preludes::storage::*;
+/// # use core::convert::Infallible;
+/// # struct At24c32([u8; 4096]);
+/// # impl embedded_hal::storage::eeprom::Eeprom for At24c32 {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Infallible> {
+/// #         let offset = offset as usize;
+/// #         bytes.copy_from_slice(&self.0[offset..offset + bytes.len()]);
+/// #         Ok(())
+/// #     }
+/// #     fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Infallible> {
+/// #         let offset = offset as usize;
+/// #         self.0[offset..offset + bytes.len()].copy_from_slice(bytes);
+/// #         Ok(())
+/// #     }
+/// #     fn capacity(&self) -> usize { self.0.len() }
+/// # }
+///
+/// let mut eeprom = At24c32([0xFF; 4096]);
+/// eeprom.try_write(0x10, &[1, 2, 3]).unwrap();
+///
+/// let mut bytes = [0; 3];
+/// eeprom.try_read(0x10, &mut bytes).unwrap();
+/// assert_eq!(bytes, [1, 2, 3]);
+/// ```
+#[cfg(feature = "storage")]
+pub mod storage {
+    pub use crate::storage::nor_flash::NorFlash as _embedded_hal_storage_nor_flash_NorFlash;
+    pub use crate::storage::nor_flash::ReadNorFlash as _embedded_hal_storage_nor_flash_ReadNorFlash;
+    pub use crate::storage::otp::Otp as _embedded_hal_storage_otp_Otp;
+    pub use crate::storage::ReadStorage as _embedded_hal_storage_ReadStorage;
+    pub use crate::storage::Storage as _embedded_hal_storage_Storage;
+}
+
+/// Synchronous serial interface traits
+#[cfg(feature = "ssi")]
+pub mod ssi {
+    pub use crate::ssi::Ssi as _embedded_hal_ssi_Ssi;
+}
+
+/// Time unit traits
+pub mod time {
+    pub use crate::time::U32Ext as _embedded_hal_time_U32Ext;
+}
+
+/// Timer traits
+pub mod timer {
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Alarm as _embedded_hal_timer_Alarm;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Cancel as _embedded_hal_timer_Cancel;
+    pub use crate::timer::Clock as _embedded_hal_timer_Clock;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::CountDown as _embedded_hal_timer_CountDown;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Elapsed as _embedded_hal_timer_Elapsed;
+    pub use crate::timer::FreeRunning as _embedded_hal_timer_FreeRunning;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Pause as _embedded_hal_timer_Pause;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Periodic as _embedded_hal_timer_Periodic;
+    pub use crate::timer::PulseCounter as _embedded_hal_timer_PulseCounter;
+    #[cfg(feature = "nb")]
+    pub use crate::timer::Remaining as _embedded_hal_timer_Remaining;
+    pub use crate::timer::SetClockSource as _embedded_hal_timer_SetClockSource;
+    pub use crate::timer::SetTrigger as _embedded_hal_timer_SetTrigger;
+}
+
+/// Watchdog traits
+#[cfg(feature = "watchdog")]
+pub mod watchdog {
+    pub use crate::watchdog::Watchdog as _embedded_hal_watchdog_Watchdog;
+    pub use crate::watchdog::WatchdogDisable as _embedded_hal_watchdog_WatchdogDisable;
+    pub use crate::watchdog::WatchdogEnable as _embedded_hal_watchdog_WatchdogEnable;
+    pub use crate::watchdog::WatchdogFreeze as _embedded_hal_watchdog_WatchdogFreeze;
+    pub use crate::watchdog::WatchdogRemaining as _embedded_hal_watchdog_WatchdogRemaining;
+    pub use crate::watchdog::WindowWatchdog as _embedded_hal_watchdog_WindowWatchdog;
+}
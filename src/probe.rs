@@ -0,0 +1,116 @@
+//! Debug probe
+//!
+//! Traits for the wire protocols of debug ports, so CMSIS-DAP style probe firmware can run on top
+//! of any implementation: GPIO bit-banging, SPI tricks or a dedicated peripheral.
+//!
+//! - [`Swd`] issues Serial Wire Debug transfers to the debug port (DP) and the access ports (AP)
+//!   of an Arm target.
+//! - [`Jtag`] shifts the instruction and data registers of a JTAG scan chain.
+//!
+//! [`Swd`]: trait.Swd.html
+//! [`Jtag`]: trait.Jtag.html
+
+/// Port addressed by an SWD transfer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Port {
+    /// Debug port (DP)
+    Debug,
+    /// Access port (AP) selected by the `SELECT` register of the debug port
+    Access,
+}
+
+/// Serial Wire Debug host
+///
+/// # Examples
+///
+/// Reading the identification register of a target
+///
+/// ```
+/// use embedded_hal::probe::{Port, Swd};
+///
+/// const DPIDR: u8 = 0x0;
+///
+/// fn dpidr<P: Swd>(probe: &mut P) -> Result<u32, P::Error> {
+///     probe.try_jtag_to_swd()?;
+///     // reading DPIDR is required after a line reset
+///     probe.try_read(Port::Debug, DPIDR)
+/// }
+///
+/// let mut probe: Probe = {
+///     // ..
+/// #   Probe
+/// };
+///
+/// assert_eq!(dpidr(&mut probe), Ok(0x2BA0_1477));
+///
+/// # struct Probe;
+/// # impl Swd for Probe {
+/// #     type Error = ();
+/// #     fn try_sequence(&mut self, _: u64, _: u8) -> Result<(), ()> { Ok(()) }
+/// #     fn try_read(&mut self, _: Port, _: u8) -> Result<u32, ()> { Ok(0x2BA0_1477) }
+/// #     fn try_write(&mut self, _: Port, _: u8, _: u32) -> Result<(), ()> { Ok(()) }
+/// # }
+/// ```
+pub trait Swd {
+    /// An enumeration of SWD errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *wait*, the target answered `WAIT` more times than the implementation retries
+    /// - *fault*, the target answered `FAULT`; the sticky error flags must be cleared
+    /// - *no response*, no valid acknowledgement was received
+    /// - *parity*, the parity bit of the data read is wrong
+    type Error;
+
+    /// Clocks the `len` lowest bits of `bits` out on SWDIO, least significant bit first
+    ///
+    /// `len` is at most 64.
+    fn try_sequence(&mut self, bits: u64, len: u8) -> Result<(), Self::Error>;
+
+    /// Reads the register at `address` of `port`
+    ///
+    /// Only the bits 2 and 3 of `address` are sent, as `A[3:2]`.
+    fn try_read(&mut self, port: Port, address: u8) -> Result<u32, Self::Error>;
+
+    /// Writes `value` to the register at `address` of `port`
+    fn try_write(&mut self, port: Port, address: u8, value: u32) -> Result<(), Self::Error>;
+
+    /// Resets the line: 50 cycles with SWDIO high followed by 2 idle cycles
+    fn try_line_reset(&mut self) -> Result<(), Self::Error> {
+        self.try_sequence((1 << 50) - 1, 52)
+    }
+
+    /// Switches a SWJ-DP target from JTAG to SWD, leaving the line reset
+    fn try_jtag_to_swd(&mut self) -> Result<(), Self::Error> {
+        self.try_sequence((1 << 50) - 1, 50)?;
+        self.try_sequence(0xE79E, 16)?;
+        self.try_line_reset()
+    }
+}
+
+/// JTAG host
+///
+/// Scans end in the Run-Test/Idle state.
+pub trait Jtag {
+    /// An enumeration of JTAG errors
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Moves the TAP to Test-Logic-Reset and then Run-Test/Idle
+    fn try_reset(&mut self) -> Result<(), Self::Error>;
+
+    /// Shifts the `bits` lowest bits of `data` through the instruction register
+    ///
+    /// The bits are shifted out of TDI least significant bit of `data[0]` first, and replaced by
+    /// the bits captured on TDO.
+    fn try_shift_ir(&mut self, data: &mut [u8], bits: usize) -> Result<(), Self::Error>;
+
+    /// Shifts the `bits` lowest bits of `data` through the data register
+    ///
+    /// See `try_shift_ir` for the bit order.
+    fn try_shift_dr(&mut self, data: &mut [u8], bits: usize) -> Result<(), Self::Error>;
+
+    /// Clocks TCK `cycles` times in Run-Test/Idle
+    fn try_idle(&mut self, cycles: u32) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,595 @@
+//! Pulse Width Modulation
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "nb")]
+use nb;
+
+pub mod output;
+pub mod servo;
+#[cfg(feature = "nb")]
+pub mod soft;
+pub mod split;
+
+use crate::time::{Duration, Rate};
+
+/// Pulse Width Modulation
+///
+/// # Examples
+///
+/// Use this interface to control the power output of some actuator
+///
+/// ```
+/// extern crate embedded_hal as hal;
+///
+/// use hal::prelude::*;
+///
+/// fn main() {
+///     let mut pwm: Pwm1 = {
+///         // ..
+/// #       Pwm1
+///     };
+///
+///     pwm.try_set_period(1.khz()).unwrap();
+///
+///     let max_duty = pwm.try_get_max_duty().unwrap();
+///
+///     // brightest LED
+///     pwm.try_set_duty(&Channel::_1, max_duty).unwrap();
+///
+///     // dimmer LED
+///     pwm.try_set_duty(&Channel::_2, max_duty / 4).unwrap();
+/// }
+///
+/// # use core::convert::Infallible;
+/// # use hal::time::Rate;
+/// # enum Channel { _1, _2 }
+/// # struct Pwm1;
+/// # impl hal::pwm::Pwm for Pwm1 {
+/// #     type Error = Infallible;
+/// #     type Channel = Channel;
+/// #     type Time = Rate;
+/// #     type Duty = u16;
+/// #     fn try_disable(&mut self, _: &Channel) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn try_enable(&mut self, _: &Channel) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn try_get_duty(&self, _: &Channel) -> Result<u16, Self::Error> { unimplemented!() }
+/// #     fn try_get_max_duty(&self) -> Result<u16, Self::Error> { Ok(0) }
+/// #     fn try_set_duty(&mut self, _: &Channel, _: u16) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_get_period(&self) -> Result<Rate, Self::Error> { unimplemented!() }
+/// #     fn try_set_period<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Rate> { Ok(()) }
+/// # }
+/// ```
+// unproven reason: pre-singletons API. The `PwmPin` trait seems more useful because it models independent
+// PWM channels. Here a certain number of channels are multiplexed in a single implementer.
+pub trait Pwm {
+    /// Enumeration of `Pwm` errors
+    type Error;
+
+    /// Enumeration of channels that can be used with this `Pwm` interface
+    ///
+    /// If your `Pwm` interface has no channels you can use the type `()`
+    /// here
+    ///
+    /// Channels are passed by reference, so the channel type doesn't need to be `Copy` and can
+    /// own resources, e.g. the pin routed to the channel.
+    type Channel;
+
+    /// A time unit that can be converted into a human time unit (e.g. seconds)
+    type Time;
+
+    /// Type for the `duty` methods
+    ///
+    /// The implementer is free to choose a float / percentage representation
+    /// (e.g. `0.0 .. 1.0`) or an integer representation (e.g. `0 .. 65535`)
+    type Duty;
+
+    /// Disables a PWM `channel`
+    fn try_disable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
+
+    /// Enables a PWM `channel`
+    fn try_enable(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
+
+    /// Returns the current PWM period
+    fn try_get_period(&self) -> Result<Self::Time, Self::Error>;
+
+    /// Returns the current duty cycle
+    fn try_get_duty(&self, channel: &Self::Channel) -> Result<Self::Duty, Self::Error>;
+
+    /// Returns the maximum duty cycle value
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error>;
+
+    /// Sets a new duty cycle
+    fn try_set_duty(
+        &mut self,
+        channel: &Self::Channel,
+        duty: Self::Duty,
+    ) -> Result<(), Self::Error>;
+
+    /// Sets a new PWM period
+    fn try_set_period<P>(&mut self, period: P) -> Result<(), Self::Error>
+    where
+        P: Into<Self::Time>;
+
+    /// Sets the duty cycle of `channel` to `num / denom` of the maximum duty cycle
+    ///
+    /// The duty cycle is rounded to the nearest value and saturates at the maximum duty cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero.
+    fn try_set_duty_fraction(
+        &mut self,
+        channel: &Self::Channel,
+        num: u32,
+        denom: u32,
+    ) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        let duty = scale(self.try_get_max_duty()?, num, denom);
+        self.try_set_duty(channel, duty)
+    }
+
+    /// Sets the duty cycle of `channel` to `percent` % of the maximum duty cycle
+    ///
+    /// The duty cycle saturates at 100 %.
+    fn try_set_duty_percent(
+        &mut self,
+        channel: &Self::Channel,
+        percent: u8,
+    ) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        self.try_set_duty_fraction(channel, u32::from(percent), 100)
+    }
+}
+
+/// PWM with complementary outputs and dead-time insertion
+///
+/// Each channel drives a pair of outputs, e.g. the high-side and low-side switches of a half
+/// bridge. The complementary output is the inverse of the main output, except that both outputs
+/// are held inactive for the dead time around every transition so that the two switches never
+/// conduct at the same time.
+///
+/// # Examples
+///
+/// Drive a half bridge
+///
+/// ```
+/// use embedded_hal::pwm::Complementary;
+/// use embedded_hal::time::U32Ext;
+///
+/// fn half_bridge<P: Complementary>(pwm: &mut P, phase: &P::Channel) -> Result<(), P::Error> {
+///     // the gate driver needs 500 ns to turn a switch off
+///     pwm.try_set_dead_time(500.nanos())?;
+///     pwm.try_enable(phase)?;
+///     pwm.try_enable_complementary(phase)
+/// }
+/// ```
+pub trait Complementary: Pwm {
+    /// Enables the complementary output of `channel`
+    fn try_enable_complementary(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
+
+    /// Disables the complementary output of `channel`
+    fn try_disable_complementary(&mut self, channel: &Self::Channel) -> Result<(), Self::Error>;
+
+    /// Returns the dead time inserted between the main and complementary outputs
+    fn try_get_dead_time(&self) -> Result<Duration, Self::Error>;
+
+    /// Sets the dead time inserted between the main and complementary outputs
+    ///
+    /// Implementations round `dead_time` up to the closest dead time supported by the hardware.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `dead_time` is longer than the longest supported dead time.
+    fn try_set_dead_time<T>(&mut self, dead_time: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>;
+}
+
+/// PWM with a configurable phase offset per channel
+///
+/// The phase delays the start of the pulse of a channel relative to the start of the period.
+/// It's expressed in the same unit as the duty cycle: `try_get_max_duty()` is a full period.
+///
+/// # Examples
+///
+/// Interleave the two phases of a converter
+///
+/// ```
+/// use embedded_hal::pwm::SetPhase;
+///
+/// fn interleave<P>(pwm: &mut P, a: &P::Channel, b: &P::Channel) -> Result<(), P::Error>
+/// where
+///     P: SetPhase<Duty = u16>,
+/// {
+///     let half_period = pwm.try_get_max_duty()? / 2;
+///     pwm.try_set_phase(a, 0)?;
+///     pwm.try_set_phase(b, half_period)
+/// }
+/// ```
+pub trait SetPhase: Pwm {
+    /// Returns the phase offset of `channel`
+    fn try_get_phase(&self, channel: &Self::Channel) -> Result<Self::Duty, Self::Error>;
+
+    /// Sets the phase offset of `channel`
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `phase` is greater than the maximum duty cycle.
+    fn try_set_phase(
+        &mut self,
+        channel: &Self::Channel,
+        phase: Self::Duty,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Alignment of the PWM pulses within the period
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Alignment {
+    /// Pulses start at the beginning of the period (the counter counts up)
+    Edge,
+    /// Pulses are centered in the period (the counter counts up then down)
+    ///
+    /// For the same period, the counter runs twice as fast as in `Edge` alignment.
+    Center,
+}
+
+/// PWM whose pulse alignment can be configured
+///
+/// # Examples
+///
+/// Motor control algorithms sample the phase currents in the middle of the period, which requires
+/// center-aligned PWM
+///
+/// ```
+/// use embedded_hal::pwm::{Alignment, SetAlignment};
+///
+/// fn setup<P: SetAlignment>(pwm: &mut P) -> Result<(), P::Error> {
+///     pwm.try_set_alignment(Alignment::Center)
+/// }
+/// ```
+pub trait SetAlignment {
+    /// An enumeration of `SetAlignment` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Returns the current alignment
+    fn try_get_alignment(&self) -> Result<Alignment, Self::Error>;
+
+    /// Sets the alignment of the pulses of all the channels
+    fn try_set_alignment(&mut self, alignment: Alignment) -> Result<(), Self::Error>;
+}
+
+/// Active level of a break input
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreakPolarity {
+    /// A low level on the input trips the break
+    ActiveLow,
+    /// A high level on the input trips the break
+    ActiveHigh,
+}
+
+/// PWM with a break (fault) input
+///
+/// When the break input trips, the hardware forces all the outputs to their inactive state without
+/// software intervention, e.g. on an overcurrent signal from a motor driver. The outputs stay
+/// disabled until the break is re-armed.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::pwm::{Break, BreakPolarity};
+///
+/// fn supervise<P: Break>(pwm: &mut P) -> Result<(), P::Error> {
+///     // the gate driver pulls its fault output low
+///     pwm.try_enable_break(BreakPolarity::ActiveLow)?;
+///
+///     // ..
+///
+///     if pwm.try_is_tripped()? {
+///         // log the fault, wait for the power stage to cool down, then
+///         pwm.try_rearm()?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Break {
+    /// An enumeration of `Break` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Enables the break input
+    fn try_enable_break(&mut self, polarity: BreakPolarity) -> Result<(), Self::Error>;
+
+    /// Disables the break input
+    fn try_disable_break(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if the break tripped and the outputs are disabled
+    fn try_is_tripped(&self) -> Result<bool, Self::Error>;
+
+    /// Re-enables the outputs after the break tripped
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the break input is still active.
+    fn try_rearm(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A single PWM channel / pin
+///
+/// See `Pwm` for details
+pub trait PwmPin {
+    /// Enumeration of `PwmPin` errors
+    type Error;
+
+    /// Type for the `duty` methods
+    ///
+    /// The implementer is free to choose a float / percentage representation
+    /// (e.g. `0.0 .. 1.0`) or an integer representation (e.g. `0 .. 65535`)
+    type Duty;
+
+    /// Disables a PWM `channel`
+    fn try_disable(&mut self) -> Result<(), Self::Error>;
+
+    /// Enables a PWM `channel`
+    fn try_enable(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the current duty cycle
+    fn try_get_duty(&self) -> Result<Self::Duty, Self::Error>;
+
+    /// Returns the maximum duty cycle value
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error>;
+
+    /// Sets a new duty cycle
+    fn try_set_duty(&mut self, duty: Self::Duty) -> Result<(), Self::Error>;
+
+    /// Sets the duty cycle to `num / denom` of the maximum duty cycle
+    ///
+    /// The duty cycle is rounded to the nearest value and saturates at the maximum duty cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_hal::pwm::PwmPin;
+    ///
+    /// fn dim<P>(led: &mut P) -> Result<(), P::Error>
+    /// where
+    ///     P: PwmPin<Duty = u16>,
+    /// {
+    ///     // a third of the full brightness
+    ///     led.try_set_duty_fraction(1, 3)
+    /// }
+    /// ```
+    fn try_set_duty_fraction(&mut self, num: u32, denom: u32) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        let duty = scale(self.try_get_max_duty()?, num, denom);
+        self.try_set_duty(duty)
+    }
+
+    /// Sets the duty cycle to `percent` % of the maximum duty cycle
+    ///
+    /// The duty cycle saturates at 100 %.
+    fn try_set_duty_percent(&mut self, percent: u8) -> Result<(), Self::Error>
+    where
+        Self::Duty: Into<u32> + TryFrom<u32>,
+    {
+        self.try_set_duty_fraction(u32::from(percent), 100)
+    }
+}
+
+/// A PWM pin that can generate a finite number of pulses
+///
+/// # Examples
+///
+/// Move a stepper motor driver by 200 steps
+///
+/// ```
+/// use embedded_hal::pwm::OnePulse;
+///
+/// fn step<P: OnePulse>(step_pin: &mut P) -> Result<(), P::Error> {
+///     step_pin.try_start_burst(200)?;
+///     nb::block!(step_pin.try_wait())
+/// }
+/// ```
+#[cfg(feature = "nb")]
+pub trait OnePulse: PwmPin {
+    /// Generates a single pulse of width `width`, then stops
+    fn try_start_pulse<T>(&mut self, width: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>;
+
+    /// Generates `count` pulses with the current period and duty cycle, then stops
+    fn try_start_burst(&mut self, count: u32) -> Result<(), Self::Error>;
+
+    /// Waits until the last pulse is over
+    ///
+    /// Returns `WouldBlock` while pulses are being generated.
+    fn try_wait(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// Tone generator, e.g. a piezo buzzer
+///
+/// Any channel of a `Pwm` can be used as a tone generator by wrapping it in a [`PwmTone`].
+///
+/// [`PwmTone`]: struct.PwmTone.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::delay::DelayNs;
+/// use embedded_hal::pwm::Tone;
+/// use embedded_hal::time::U32Ext;
+///
+/// fn beep<T: Tone, D: DelayNs>(buzzer: &mut T, delay: &mut D) -> Result<(), T::Error> {
+///     buzzer.try_play(2.khz())?;
+///     let _ = delay.try_delay_millis(100);
+///     buzzer.try_stop()
+/// }
+/// ```
+pub trait Tone {
+    /// An enumeration of `Tone` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Starts playing a square wave at `frequency`, until `try_stop` is called
+    fn try_play<F>(&mut self, frequency: F) -> Result<(), Self::Error>
+    where
+        F: Into<Rate>;
+
+    /// Stops playing
+    fn try_stop(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Tone generator on a channel of a [`Pwm`]
+///
+/// Implements [`Tone`] by setting the PWM frequency and a 50 % duty cycle on the channel.
+///
+/// *NOTE* The frequency is shared by all the channels of the `Pwm`: playing a tone changes the
+/// frequency of the other channels as well.
+///
+/// [`Pwm`]: trait.Pwm.html
+/// [`Tone`]: trait.Tone.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::pwm::{PwmTone, Tone};
+/// use embedded_hal::time::U32Ext;
+///
+/// let mut buzzer = PwmTone::new(Tim3, Channel::_1);
+///
+/// buzzer.try_play(2.khz()).unwrap();
+/// buzzer.try_stop().unwrap();
+///
+/// // get the timer back
+/// let (tim3, _) = buzzer.free();
+///
+/// # use core::convert::Infallible;
+/// # use embedded_hal::time::Rate;
+/// # enum Channel { _1 }
+/// # struct Tim3;
+/// # impl embedded_hal::pwm::Pwm for Tim3 {
+/// #     type Error = Infallible;
+/// #     type Channel = Channel;
+/// #     type Time = Rate;
+/// #     type Duty = u16;
+/// #     fn try_disable(&mut self, _: &Channel) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_enable(&mut self, _: &Channel) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_get_duty(&self, _: &Channel) -> Result<u16, Self::Error> { Ok(0) }
+/// #     fn try_get_max_duty(&self) -> Result<u16, Self::Error> { Ok(1000) }
+/// #     fn try_set_duty(&mut self, _: &Channel, _: u16) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_get_period(&self) -> Result<Rate, Self::Error> { unimplemented!() }
+/// #     fn try_set_period<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Rate> { Ok(()) }
+/// # }
+/// ```
+pub struct PwmTone<P>
+where
+    P: Pwm,
+{
+    pwm: P,
+    channel: P::Channel,
+}
+
+impl<P> PwmTone<P>
+where
+    P: Pwm,
+{
+    /// Plays tones on `channel` of `pwm`
+    pub fn new(pwm: P, channel: P::Channel) -> Self {
+        PwmTone { pwm, channel }
+    }
+
+    /// Releases the `Pwm` and the channel
+    pub fn free(self) -> (P, P::Channel) {
+        (self.pwm, self.channel)
+    }
+}
+
+impl<P> Tone for PwmTone<P>
+where
+    P: Pwm,
+    P::Duty: Into<u32> + TryFrom<u32>,
+    Rate: Into<P::Time>,
+{
+    type Error = P::Error;
+
+    fn try_play<F>(&mut self, frequency: F) -> Result<(), Self::Error>
+    where
+        F: Into<Rate>,
+    {
+        self.pwm.try_set_period(frequency.into())?;
+        self.pwm.try_set_duty_percent(&self.channel, 50)?;
+        self.pwm.try_enable(&self.channel)
+    }
+
+    fn try_stop(&mut self) -> Result<(), Self::Error> {
+        self.pwm.try_disable(&self.channel)
+    }
+}
+
+/// Scales `max` by `num / denom`, rounding to the nearest value and saturating at `max`
+fn scale<D>(max: D, num: u32, denom: u32) -> D
+where
+    D: Into<u32> + TryFrom<u32>,
+{
+    let max = u64::from(max.into());
+    let num = u64::from(num.min(denom));
+    let denom = u64::from(denom);
+
+    // can't overflow: `num <= denom` so the result is at most `max`, which came from a `D`
+    let duty = (max * num + denom / 2) / denom;
+    match D::try_from(duty as u32) {
+        Ok(duty) => duty,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// A PWM pin whose frequency can be configured
+///
+/// Lets drivers request the frequency they need (e.g. 50 Hz for hobby servos) instead of relying on
+/// the application to configure it.
+///
+/// *NOTE* PWM pins usually share a timer with other pins: changing the frequency of a pin can
+/// change the frequency of all the pins of the same timer. Implementations document which pins
+/// are affected.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::pwm::SetFrequency;
+/// use embedded_hal::time::U32Ext;
+///
+/// fn servo_init<P: SetFrequency>(pin: &mut P) -> Result<(), P::Error> {
+///     pin.try_set_frequency(50.hz())?;
+///     pin.try_enable()
+/// }
+/// ```
+pub trait SetFrequency: PwmPin {
+    /// Returns the current frequency
+    fn try_get_frequency(&self) -> Result<Rate, Self::Error>;
+
+    /// Sets the frequency
+    ///
+    /// Changing the frequency can change the maximum duty cycle; the duty cycle must be set again
+    /// afterwards.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the frequency can't be generated.
+    fn try_set_frequency<F>(&mut self, frequency: F) -> Result<(), Self::Error>
+    where
+        F: Into<Rate>;
+}
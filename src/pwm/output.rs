@@ -0,0 +1,94 @@
+//! Using a PWM pin as a digital output
+//!
+//! [`PwmOutput`] drives a `PwmPin` at 0 % or 100 % duty cycle, so drivers that need a plain
+//! `OutputPin` (e.g. a chip enable or a backlight switch) can use a pin routed to a timer channel.
+//!
+//! [`PwmOutput`]: struct.PwmOutput.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::digital::OutputPin;
+//! use embedded_hal::pwm::output::PwmOutput;
+//! use embedded_hal::pwm::PwmPin;
+//!
+//! let mut backlight: Tim3Ch1 = {
+//!     // ..
+//! #   Tim3Ch1
+//! };
+//! backlight.try_enable().unwrap();
+//!
+//! let mut backlight = PwmOutput::new(backlight);
+//! backlight.try_set_high().unwrap();
+//!
+//! # use core::convert::Infallible;
+//! # struct Tim3Ch1;
+//! # impl PwmPin for Tim3Ch1 {
+//! #     type Error = Infallible;
+//! #     type Duty = u16;
+//! #     fn try_disable(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_enable(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_get_duty(&self) -> Result<u16, Infallible> { Ok(0) }
+//! #     fn try_get_max_duty(&self) -> Result<u16, Infallible> { Ok(1000) }
+//! #     fn try_set_duty(&mut self, _: u16) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! ```
+
+use super::PwmPin;
+use crate::digital::{OutputPin, StatefulOutputPin};
+
+/// A `PwmPin` used as an `OutputPin`
+///
+/// The pin must be enabled for its output to follow the level set through this adapter.
+pub struct PwmOutput<P> {
+    pin: P,
+}
+
+impl<P> PwmOutput<P>
+where
+    P: PwmPin,
+    P::Duty: Default,
+{
+    /// Wraps `pin`
+    pub fn new(pin: P) -> Self {
+        PwmOutput { pin }
+    }
+
+    /// Releases the pin
+    pub fn free(self) -> P {
+        self.pin
+    }
+}
+
+impl<P> OutputPin for PwmOutput<P>
+where
+    P: PwmPin,
+    P::Duty: Default,
+{
+    type Error = P::Error;
+
+    /// Sets the duty cycle to 0 %
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_set_duty(P::Duty::default())
+    }
+
+    /// Sets the duty cycle to 100 %
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        let max_duty = self.pin.try_get_max_duty()?;
+        self.pin.try_set_duty(max_duty)
+    }
+}
+
+impl<P> StatefulOutputPin for PwmOutput<P>
+where
+    P: PwmPin,
+    P::Duty: Default + PartialEq,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.pin.try_get_duty()? == self.pin.try_get_max_duty()?)
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.pin.try_get_duty()? == P::Duty::default())
+    }
+}
@@ -0,0 +1,139 @@
+//! RC servos
+//!
+//! Hobby servos expect a pulse every 20 ms (50 Hz) whose width, typically between 1 ms and 2 ms,
+//! sets the angle of the horn. [`Servo`] drives a servo from a `PwmPin` whose frequency can be
+//! set, with per-servo calibration limits.
+//!
+//! [`Servo`]: struct.Servo.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::pwm::servo::{Calibration, Servo};
+//!
+//! let pin: Tim2Ch1 = {
+//!     // ..
+//! #   Tim2Ch1
+//! };
+//!
+//! // this servo reaches its end stops at 0.6 ms and 2.4 ms
+//! let calibration = Calibration {
+//!     min_pulse_us: 600,
+//!     max_pulse_us: 2_400,
+//!     max_angle: 180,
+//! };
+//!
+//! let mut servo = Servo::new(pin, calibration).unwrap();
+//! servo.try_set_angle(90).unwrap();
+//!
+//! # use core::convert::Infallible;
+//! # use embedded_hal::pwm::{PwmPin, SetFrequency};
+//! # use embedded_hal::time::Rate;
+//! # struct Tim2Ch1;
+//! # impl PwmPin for Tim2Ch1 {
+//! #     type Error = Infallible;
+//! #     type Duty = u16;
+//! #     fn try_disable(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_enable(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_get_duty(&self) -> Result<u16, Infallible> { Ok(0) }
+//! #     fn try_get_max_duty(&self) -> Result<u16, Infallible> { Ok(20_000) }
+//! #     fn try_set_duty(&mut self, _: u16) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! # impl SetFrequency for Tim2Ch1 {
+//! #     fn try_get_frequency(&self) -> Result<Rate, Infallible> { Ok(Rate::from_hz(50)) }
+//! #     fn try_set_frequency<F>(&mut self, _: F) -> Result<(), Infallible> where F: Into<Rate> { Ok(()) }
+//! # }
+//! ```
+
+use core::convert::TryFrom;
+
+use super::{scale, SetFrequency};
+use crate::time::Rate;
+
+/// Frequency of the servo pulses
+const FREQUENCY_HZ: u32 = 50;
+
+/// Calibration limits of a servo
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Calibration {
+    /// Pulse width at 0 degrees, in microseconds
+    pub min_pulse_us: u32,
+    /// Pulse width at `max_angle`, in microseconds
+    pub max_pulse_us: u32,
+    /// Angle reached with `max_pulse_us`, in degrees
+    pub max_angle: u16,
+}
+
+impl Default for Calibration {
+    /// The nominal 1 ms to 2 ms range, for 180 degrees
+    fn default() -> Self {
+        Calibration {
+            min_pulse_us: 1_000,
+            max_pulse_us: 2_000,
+            max_angle: 180,
+        }
+    }
+}
+
+/// An RC servo driven by a PWM pin
+pub struct Servo<P> {
+    pin: P,
+    calibration: Calibration,
+}
+
+impl<P> Servo<P>
+where
+    P: SetFrequency,
+    P::Duty: Into<u32> + TryFrom<u32>,
+{
+    /// Sets the frequency of `pin` to 50 Hz and enables it
+    ///
+    /// The pulse width is set to the middle of the calibrated range.
+    pub fn new(pin: P, calibration: Calibration) -> Result<Self, P::Error> {
+        let mut servo = Servo { pin, calibration };
+
+        servo.pin.try_set_frequency(Rate::from_hz(FREQUENCY_HZ))?;
+        servo.try_set_angle(calibration.max_angle / 2)?;
+        servo.pin.try_enable()?;
+
+        Ok(servo)
+    }
+
+    /// Releases the pin
+    pub fn free(self) -> P {
+        self.pin
+    }
+
+    /// Sets the pulse width, clamped to the calibrated range
+    ///
+    /// The duty cycle is computed from the frequency reported by the pin, as the hardware may
+    /// not reach exactly 50 Hz.
+    pub fn try_set_pulse_width_us(&mut self, us: u32) -> Result<(), P::Error> {
+        let us = us
+            .max(self.calibration.min_pulse_us)
+            .min(self.calibration.max_pulse_us);
+        let period_us = self.pin.try_get_frequency()?.period().as_micros() as u32;
+
+        let duty = scale(self.pin.try_get_max_duty()?, us, period_us);
+        self.pin.try_set_duty(duty)
+    }
+
+    /// Sets the angle of the servo in degrees, clamped to the calibrated range
+    pub fn try_set_angle(&mut self, degrees: u16) -> Result<(), P::Error> {
+        let Calibration {
+            min_pulse_us,
+            max_pulse_us,
+            max_angle,
+        } = self.calibration;
+
+        let degrees = u32::from(degrees.min(max_angle));
+        let range = max_pulse_us.saturating_sub(min_pulse_us);
+        let offset = if max_angle == 0 {
+            0
+        } else {
+            range * degrees / u32::from(max_angle)
+        };
+
+        self.try_set_pulse_width_us(min_pulse_us + offset)
+    }
+}
@@ -0,0 +1,199 @@
+//! Software PWM
+//!
+//! [`SoftPwm`] generates a PWM signal on any `OutputPin` by toggling it when a `CountDown` timer
+//! expires. It's meant for LEDs and slow actuators on pins without hardware PWM: the accuracy of
+//! the signal depends on how quickly `try_run` is called after the timer expires.
+//!
+//! [`SoftPwm`]: struct.SoftPwm.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::pwm::soft::SoftPwm;
+//! use embedded_hal::pwm::PwmPin;
+//! use embedded_hal::time::U32Ext;
+//!
+//! let led: Led = {
+//!     // ..
+//! #   Led
+//! };
+//! let timer: Timer6 = {
+//!     // ..
+//! #   Timer6
+//! };
+//!
+//! // 100 Hz, with 100 brightness levels
+//! let mut pwm = SoftPwm::new(led, timer, 10.millis(), 100);
+//! pwm.try_set_duty(25).unwrap();
+//! pwm.try_enable().unwrap();
+//!
+//! loop {
+//!     // e.g. from the main loop or from the interrupt handler of the timer
+//!     let _ = pwm.try_run();
+//! #   break;
+//! }
+//!
+//! # use core::convert::Infallible;
+//! # use embedded_hal::time::Duration;
+//! # struct Led;
+//! # impl embedded_hal::digital::OutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Infallible> { Ok(()) }
+//! # }
+//! # struct Timer6;
+//! # impl embedded_hal::timer::CountDown for Timer6 {
+//! #     type Error = Infallible;
+//! #     type Time = Duration;
+//! #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Duration> { Ok(()) }
+//! #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+//! # }
+//! ```
+
+use nb;
+
+use super::PwmPin;
+use crate::digital::OutputPin;
+use crate::time::Duration;
+use crate::timer::CountDown;
+
+/// Software PWM error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error<PE, TE> {
+    /// Error reported by the output pin
+    Pin(PE),
+    /// Error reported by the timer
+    Timer(TE),
+}
+
+/// PWM generated in software on an `OutputPin`
+pub struct SoftPwm<P, T> {
+    pin: P,
+    timer: T,
+    period: Duration,
+    duty: u16,
+    max_duty: u16,
+    enabled: bool,
+    high: bool,
+}
+
+impl<P, T> SoftPwm<P, T>
+where
+    P: OutputPin,
+    T: CountDown,
+    Duration: Into<T::Time>,
+{
+    /// Creates a disabled software PWM with the given `period` and `max_duty` duty cycle steps
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_duty` is zero.
+    pub fn new<D>(pin: P, timer: T, period: D, max_duty: u16) -> Self
+    where
+        D: Into<Duration>,
+    {
+        assert!(max_duty > 0);
+
+        SoftPwm {
+            pin,
+            timer,
+            period: period.into(),
+            duty: 0,
+            max_duty,
+            enabled: false,
+            high: false,
+        }
+    }
+
+    /// Releases the pin and the timer
+    pub fn free(self) -> (P, T) {
+        (self.pin, self.timer)
+    }
+
+    /// Drives the pin
+    ///
+    /// Must be called repeatedly, e.g. from the main loop or from the interrupt handler of the
+    /// timer. Returns `WouldBlock` until the timer expires, then toggles the pin as needed and
+    /// restarts the timer. Always returns `WouldBlock` while the PWM is disabled.
+    pub fn try_run(&mut self) -> nb::Result<(), Error<P::Error, T::Error>> {
+        if !self.enabled {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        match self.timer.try_wait() {
+            Ok(()) => {}
+            Err(nb::Error::WouldBlock) => return Err(nb::Error::WouldBlock),
+            Err(nb::Error::Other(e)) => return Err(nb::Error::Other(Error::Timer(e))),
+        }
+
+        self.next_phase().map_err(nb::Error::Other)
+    }
+
+    fn next_phase(&mut self) -> Result<(), Error<P::Error, T::Error>> {
+        let period = self.period.as_nanos();
+        let on = Duration::from_nanos(period * u64::from(self.duty) / u64::from(self.max_duty));
+        let off = self.period - on;
+
+        // stay low (or high) for whole periods at 0% (or 100%) duty cycle
+        let (high, phase) = if on.as_nanos() == 0 {
+            (false, self.period)
+        } else if off.as_nanos() == 0 {
+            (true, self.period)
+        } else if self.high {
+            (false, off)
+        } else {
+            (true, on)
+        };
+
+        if high {
+            self.pin.try_set_high().map_err(Error::Pin)?;
+        } else {
+            self.pin.try_set_low().map_err(Error::Pin)?;
+        }
+        self.high = high;
+
+        self.timer.try_start(phase).map_err(Error::Timer)
+    }
+}
+
+impl<P, T> PwmPin for SoftPwm<P, T>
+where
+    P: OutputPin,
+    T: CountDown,
+    Duration: Into<T::Time>,
+{
+    type Error = Error<P::Error, T::Error>;
+    type Duty = u16;
+
+    fn try_disable(&mut self) -> Result<(), Self::Error> {
+        self.enabled = false;
+        self.high = false;
+        self.pin.try_set_low().map_err(Error::Pin)
+    }
+
+    fn try_enable(&mut self) -> Result<(), Self::Error> {
+        if self.enabled {
+            return Ok(());
+        }
+
+        self.enabled = true;
+        self.high = false;
+        self.next_phase()
+    }
+
+    fn try_get_duty(&self) -> Result<u16, Self::Error> {
+        Ok(self.duty)
+    }
+
+    fn try_get_max_duty(&self) -> Result<u16, Self::Error> {
+        Ok(self.max_duty)
+    }
+
+    /// Sets a new duty cycle, saturating at the maximum duty cycle
+    ///
+    /// The new duty cycle takes effect at the next toggle of the pin.
+    fn try_set_duty(&mut self, duty: u16) -> Result<(), Self::Error> {
+        self.duty = duty.min(self.max_duty);
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+//! Splitting a multi-channel PWM into pins
+//!
+//! Drivers are usually written against `PwmPin`, while some HALs only implement the multiplexed
+//! `Pwm` trait. [`PwmChannel`] bridges the two: each handle drives a single channel of a `Pwm`
+//! shared through a [`Mutex`], e.g. a `RefCell`.
+//!
+//! [`PwmChannel`]: struct.PwmChannel.html
+//! [`Mutex`]: ../../mutex/trait.Mutex.html
+//!
+//! # Examples
+//!
+//! ```
+//! use core::cell::RefCell;
+//! use embedded_hal::pwm::split::PwmChannel;
+//! use embedded_hal::pwm::PwmPin;
+//!
+//! let pwm = RefCell::new(Tim3);
+//!
+//! let mut red = PwmChannel::new(&pwm, Channel::_1);
+//! let mut green = PwmChannel::new(&pwm, Channel::_2);
+//!
+//! red.try_set_duty_percent(100).unwrap();
+//! green.try_set_duty_percent(20).unwrap();
+//!
+//! # use core::convert::Infallible;
+//! # use embedded_hal::time::Rate;
+//! # enum Channel { _1, _2 }
+//! # struct Tim3;
+//! # impl embedded_hal::pwm::Pwm for Tim3 {
+//! #     type Error = Infallible;
+//! #     type Channel = Channel;
+//! #     type Time = Rate;
+//! #     type Duty = u16;
+//! #     fn try_disable(&mut self, _: &Channel) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_enable(&mut self, _: &Channel) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_get_duty(&self, _: &Channel) -> Result<u16, Self::Error> { Ok(0) }
+//! #     fn try_get_max_duty(&self) -> Result<u16, Self::Error> { Ok(1000) }
+//! #     fn try_set_duty(&mut self, _: &Channel, _: u16) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_get_period(&self) -> Result<Rate, Self::Error> { unimplemented!() }
+//! #     fn try_set_period<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Rate> { Ok(()) }
+//! # }
+//! ```
+
+use super::{Pwm, PwmPin};
+use crate::mutex::Mutex;
+
+/// A single channel of a `Pwm` shared through a `Mutex`
+///
+/// # Panics
+///
+/// With a `RefCell`, methods panic if the `Pwm` is already borrowed, e.g. when a channel is used
+/// from an interrupt handler while the main loop is using another channel.
+pub struct PwmChannel<'a, M>
+where
+    M: Mutex,
+    M::Data: Pwm,
+{
+    pwm: &'a M,
+    channel: <M::Data as Pwm>::Channel,
+}
+
+impl<'a, M> PwmChannel<'a, M>
+where
+    M: Mutex,
+    M::Data: Pwm,
+{
+    /// Creates a handle to `channel` of the shared `pwm`
+    pub fn new(pwm: &'a M, channel: <M::Data as Pwm>::Channel) -> Self {
+        PwmChannel { pwm, channel }
+    }
+}
+
+impl<'a, M> PwmPin for PwmChannel<'a, M>
+where
+    M: Mutex,
+    M::Data: Pwm,
+{
+    type Error = <M::Data as Pwm>::Error;
+    type Duty = <M::Data as Pwm>::Duty;
+
+    fn try_disable(&mut self) -> Result<(), Self::Error> {
+        let channel = &self.channel;
+        self.pwm.lock(|pwm| pwm.try_disable(channel))
+    }
+
+    fn try_enable(&mut self) -> Result<(), Self::Error> {
+        let channel = &self.channel;
+        self.pwm.lock(|pwm| pwm.try_enable(channel))
+    }
+
+    fn try_get_duty(&self) -> Result<Self::Duty, Self::Error> {
+        let channel = &self.channel;
+        self.pwm.lock(|pwm| pwm.try_get_duty(channel))
+    }
+
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error> {
+        self.pwm.lock(|pwm| pwm.try_get_max_duty())
+    }
+
+    fn try_set_duty(&mut self, duty: Self::Duty) -> Result<(), Self::Error> {
+        let channel = &self.channel;
+        self.pwm.lock(|pwm| pwm.try_set_duty(channel, duty))
+    }
+}
@@ -1,5 +1,7 @@
 //! Quadrature encoder interface
 
+use crate::time::Duration;
+
 /// Quadrature encoder interface
 ///
 /// # Examples
@@ -25,7 +27,7 @@
 ///
 ///
 ///     let before = qei.try_count().unwrap();
-///     timer.try_start(1.s()).unwrap();
+///     timer.try_start(1.secs()).unwrap();
 ///     block!(timer.try_wait());
 ///     let after = qei.try_count().unwrap();
 ///
@@ -34,9 +36,7 @@
 /// }
 ///
 /// # use core::convert::Infallible;
-/// # struct Seconds(u32);
-/// # trait U32Ext { fn s(self) -> Seconds; }
-/// # impl U32Ext for u32 { fn s(self) -> Seconds { Seconds(self) } }
+/// # use hal::time::Duration;
 /// # struct Qei1;
 /// # impl hal::qei::Qei for Qei1 {
 /// #     type Error = Infallible;
@@ -47,8 +47,8 @@
 /// # struct Timer6;
 /// # impl hal::timer::CountDown for Timer6 {
 /// #     type Error = Infallible;
-/// #     type Time = Seconds;
-/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Seconds> { Ok(()) }
+/// #     type Time = Duration;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Duration> { Ok(()) }
 /// #     fn try_wait(&mut self) -> ::nb::Result<(), Infallible> { Ok(()) }
 /// # }
 /// ```
@@ -76,3 +76,75 @@ pub enum Direction {
     /// 1, 2, 3
     Upcounting,
 }
+
+/// Quadrature encoder whose count can be set
+///
+/// # Examples
+///
+/// Homing: move until the end stop is hit, then make that position the origin
+///
+/// ```
+/// use embedded_hal::qei::SetCount;
+///
+/// fn home<Q>(qei: &mut Q) -> Result<(), Q::Error>
+/// where
+///     Q: SetCount<Count = u16>,
+/// {
+///     // .. move until the end stop is hit
+///     qei.try_reset_count()
+/// }
+/// ```
+pub trait SetCount: Qei {
+    /// Sets the current pulse count of the encoder to `count`
+    fn try_set_count(&mut self, count: Self::Count) -> Result<(), Self::Error>;
+
+    /// Resets the current pulse count of the encoder to zero
+    fn try_reset_count(&mut self) -> Result<(), Self::Error>
+    where
+        Self::Count: Default,
+    {
+        self.try_set_count(Self::Count::default())
+    }
+}
+
+/// Quadrature decoding mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CountingMode {
+    /// Counts on one edge of input A: one count per encoder cycle
+    X1,
+    /// Counts on both edges of input A: two counts per encoder cycle
+    X2,
+    /// Counts on both edges of both inputs: four counts per encoder cycle
+    X4,
+}
+
+/// Quadrature encoder whose decoding can be configured
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::qei::{Configure, CountingMode};
+/// use embedded_hal::time::U32Ext;
+///
+/// fn setup<Q: Configure>(qei: &mut Q) -> Result<(), Q::Error> {
+///     qei.try_set_counting_mode(CountingMode::X4)?;
+///     // ignore contact bounce of a mechanical encoder
+///     qei.try_set_filter(2.micros())
+/// }
+/// ```
+pub trait Configure: Qei {
+    /// Sets the quadrature decoding mode
+    fn try_set_counting_mode(&mut self, mode: CountingMode) -> Result<(), Self::Error>;
+
+    /// Ignores input pulses shorter than `min_pulse`
+    ///
+    /// Implementations round `min_pulse` up to the closest filter supported by the hardware. A
+    /// zero duration disables the filter.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `min_pulse` is longer than the longest supported filter.
+    fn try_set_filter<T>(&mut self, min_pulse: T) -> Result<(), Self::Error>
+    where
+        T: Into<Duration>;
+}
@@ -12,3 +12,50 @@ pub trait Read {
     /// Get a number of bytes from the RNG.
     fn try_read(&mut self, buf: &mut [u8]) -> nb::Result<usize, Self::Error>;
 }
+
+/// Marker for cryptographically secure random number generators
+///
+/// Implemented, next to [`Read`] and / or [`blocking::rng::Read`], by generators whose output is
+/// suitable for keys, nonces and other secrets. Security libraries require it to reject plain
+/// noise sources at compile time.
+///
+/// [`Read`]: trait.Read.html
+/// [`blocking::rng::Read`]: ../blocking/rng/trait.Read.html
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::rng::Read;
+/// use embedded_hal::rng::CryptoRng;
+///
+/// fn nonce<R: Read + CryptoRng>(rng: &mut R) -> Result<[u8; 12], R::Error> {
+///     let mut nonce = [0; 12];
+///     rng.try_read(&mut nonce)?;
+///     Ok(nonce)
+/// }
+///
+/// let mut trng: Trng = {
+///     // ..
+/// #   Trng
+/// };
+///
+/// nonce(&mut trng).unwrap();
+///
+/// # use core::convert::Infallible;
+/// # struct Trng;
+/// # impl CryptoRng for Trng {}
+/// # impl Read for Trng {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self, _: &mut [u8]) -> Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+///
+/// # Contract
+///
+/// - The output MUST be unpredictable: entropy conditioned by the hardware, or a cryptographically
+///   secure generator seeded from such a source.
+/// - The implementation MUST return an error rather than weak output when its health tests
+///   fail.
+pub trait CryptoRng {}
+
+impl<T: CryptoRng + ?Sized> CryptoRng for &mut T {}
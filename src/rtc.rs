@@ -0,0 +1,60 @@
+//! Real-time clock
+
+/// RTC digital calibration
+///
+/// RTC crystals are typically off by a few tens of ppm, i.e. a few seconds a day. Applications
+/// that measure the drift, e.g. against GPS or network time, apply a correction through this
+/// trait; the implementation picks the mechanism of the hardware (smooth calibration that masks
+/// or adds clock pulses, coarse calibration of the prescaler, ...).
+///
+/// Corrections are in parts per billion (ppb): a positive correction speeds the clock up.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::rtc::Calibrate;
+///
+/// /// Compensates a drift of `seconds` measured over `days`
+/// fn trim<R: Calibrate>(rtc: &mut R, seconds: i32, days: i32) -> Result<i32, R::Error> {
+///     // 1 s/day is 11 574 ppb
+///     let drift = seconds * 11_574 / days;
+///     let correction = rtc.try_correction()? - drift;
+///     rtc.try_set_correction(correction)
+/// }
+///
+/// let mut rtc: Rtc = {
+///     // ..
+/// #   Rtc
+/// };
+///
+/// // the RTC gained 3 seconds in a week
+/// let applied = trim(&mut rtc, 3, 7).unwrap();
+/// assert!(applied < 0);
+///
+/// # struct Rtc;
+/// # impl Calibrate for Rtc {
+/// #     type Error = ();
+/// #     fn resolution(&self) -> u32 { 954 }
+/// #     fn try_correction(&self) -> Result<i32, ()> { Ok(0) }
+/// #     fn try_set_correction(&mut self, ppb: i32) -> Result<i32, ()> { Ok(ppb / 954 * 954) }
+/// # }
+/// ```
+pub trait Calibrate {
+    /// An enumeration of calibration errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of range*, the correction exceeds what the hardware can apply
+    type Error;
+
+    /// Returns the smallest correction step, in ppb
+    fn resolution(&self) -> u32;
+
+    /// Returns the correction currently applied, in ppb
+    fn try_correction(&self) -> Result<i32, Self::Error>;
+
+    /// Applies a correction of `ppb` parts per billion, replacing the previous one
+    ///
+    /// Returns the correction actually applied, `ppb` rounded to the resolution of the hardware.
+    fn try_set_correction(&mut self, ppb: i32) -> Result<i32, Self::Error>;
+}
@@ -0,0 +1,114 @@
+//! Segment LCD
+//!
+//! Segment (glass) LCD controllers drive a matrix of segments: each segment sits at the crossing
+//! of a common (COM) and a segment (SEG) line. Drivers set segments in the display RAM of the
+//! controller with [`SegmentLcd`], then request an update to show the new frame.
+//!
+//! [`SegmentLcd`]: trait.SegmentLcd.html
+
+use core::cmp;
+
+/// Segment LCD controller
+///
+/// # Examples
+///
+/// Showing a digit on a seven segment display wired to COM0 and SEG0 to SEG6
+///
+/// ```
+/// use embedded_hal::segment_lcd::SegmentLcd;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// // segments a to g of the digits 0 to 9
+/// const DIGITS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+///
+/// fn show_digit<L: SegmentLcd>(lcd: &mut L, digit: usize) -> Result<(), L::Error> {
+///     for segment in 0..7 {
+///         let on = DIGITS[digit] & (1 << segment) != 0;
+///         lcd.try_set_segment(0, segment, on)?;
+///     }
+///
+///     block!(lcd.try_update())
+/// }
+///
+/// # fn main() {
+/// let mut lcd: Lcd = {
+///     // ..
+/// #   Lcd
+/// };
+///
+/// show_digit(&mut lcd, 7).unwrap();
+/// # }
+///
+/// # struct Lcd;
+/// # impl SegmentLcd for Lcd {
+/// #     type Error = ();
+/// #     fn commons(&self) -> u8 { 4 }
+/// #     fn segments(&self) -> u8 { 32 }
+/// #     fn try_set_segment(&mut self, _: u8, _: u8, _: bool) -> Result<(), ()> { Ok(()) }
+/// #     fn try_update(&mut self) -> nb::Result<(), ()> { Ok(()) }
+/// # }
+/// ```
+pub trait SegmentLcd {
+    /// An enumeration of LCD errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of range*, the common or segment line is not driven by the controller
+    type Error;
+
+    /// Returns the number of common lines
+    fn commons(&self) -> u8;
+
+    /// Returns the number of segment lines
+    fn segments(&self) -> u8;
+
+    /// Turns the segment at the crossing of `common` and `segment` on or off
+    ///
+    /// The change is only written to the display RAM; it becomes visible after `try_update`.
+    fn try_set_segment(&mut self, common: u8, segment: u8, on: bool) -> Result<(), Self::Error>;
+
+    /// Sets the segment lines `0..32` of `common` at once, bit `n` of `bits` being segment `n`
+    fn try_set_common(&mut self, common: u8, bits: u32) -> Result<(), Self::Error> {
+        for segment in 0..cmp::min(self.segments(), 32) {
+            self.try_set_segment(common, segment, bits & (1 << segment) != 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Turns every segment off
+    ///
+    /// Like `try_set_segment`, this only changes the display RAM.
+    fn try_clear(&mut self) -> Result<(), Self::Error> {
+        for common in 0..self.commons() {
+            for segment in 0..self.segments() {
+                self.try_set_segment(common, segment, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers the display RAM to the glass
+    ///
+    /// Returns `WouldBlock` until the controller has latched the new frame, usually at the start
+    /// of the next frame.
+    fn try_update(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// Segment LCD controller with an adjustable contrast
+pub trait Contrast {
+    /// An enumeration of contrast errors
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Returns the highest contrast level
+    fn max_contrast(&self) -> u8;
+
+    /// Sets the contrast level, from `0` (lowest) to `max_contrast()`
+    ///
+    /// Levels above `max_contrast()` are clamped.
+    fn try_set_contrast(&mut self, level: u8) -> Result<(), Self::Error>;
+}
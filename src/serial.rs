@@ -14,6 +14,17 @@ pub trait Read<Word> {
     fn try_read(&mut self) -> nb::Result<Word, Self::Error>;
 }
 
+impl<T, Word> Read<Word> for &mut T
+where
+    T: Read<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        T::try_read(self)
+    }
+}
+
 /// Write half of a serial interface
 pub trait Write<Word> {
     /// Write error
@@ -25,3 +36,18 @@ pub trait Write<Word> {
     /// Ensures that none of the previously written words are still buffered
     fn try_flush(&mut self) -> nb::Result<(), Self::Error>;
 }
+
+impl<T, Word> Write<Word> for &mut T
+where
+    T: Write<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        T::try_write(self, word)
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        T::try_flush(self)
+    }
+}
@@ -0,0 +1,86 @@
+//! Sigma-delta modulator inputs
+//!
+//! Digital microphones and isolated current-sense modulators output a 1-bit stream that must be
+//! demodulated by a digital filter (e.g. the DFSDM peripheral of STM32 microcontrollers) before it
+//! can be used as a sample.
+
+use nb;
+
+/// Order of the sinc filter that demodulates the bitstream
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FilterOrder {
+    /// Sinc1 filter
+    Sinc1,
+    /// Sinc2 filter
+    Sinc2,
+    /// Sinc3 filter
+    Sinc3,
+    /// Sinc4 filter
+    Sinc4,
+    /// Sinc5 filter
+    Sinc5,
+}
+
+/// A digital filter demodulating the bitstream of a sigma-delta modulator
+///
+/// # Examples
+///
+/// Record a digital microphone
+///
+/// ```
+/// use embedded_hal::sigma_delta::{Filter, FilterOrder};
+///
+/// fn record<F>(filter: &mut F, mic: F::Channel, samples: &mut [i32]) -> Result<(), F::Error>
+/// where
+///     F: Filter,
+/// {
+///     // 3.072 MHz microphone clock / 64 = 48 kHz
+///     filter.try_configure(mic, FilterOrder::Sinc4, 64)?;
+///     filter.try_start()?;
+///
+///     for sample in samples.iter_mut() {
+///         *sample = nb::block!(filter.try_read())?;
+///     }
+///
+///     filter.try_stop()
+/// }
+/// ```
+pub trait Filter {
+    /// An enumeration of `Filter` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Identifies the serial inputs the filter can be connected to
+    type Channel;
+
+    /// Connects the filter to `channel` and configures the filter `order` and `oversampling`
+    /// ratio
+    ///
+    /// The output data rate is the modulator clock frequency divided by `oversampling`.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the filter doesn't support this order or oversampling ratio.
+    fn try_configure(
+        &mut self,
+        channel: Self::Channel,
+        order: FilterOrder,
+        oversampling: u16,
+    ) -> Result<(), Self::Error>;
+
+    /// Starts demodulating the bitstream continuously
+    fn try_start(&mut self) -> Result<(), Self::Error>;
+
+    /// Stops demodulating the bitstream
+    fn try_stop(&mut self) -> Result<(), Self::Error>;
+
+    /// Reads the next filtered sample
+    ///
+    /// Samples are signed and right-aligned.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the filter was not started, or if a sample was lost.
+    fn try_read(&mut self) -> nb::Result<i32, Self::Error>;
+}
@@ -1,5 +1,6 @@
 //! Serial Peripheral Interface
 
+#[cfg(feature = "nb")]
 use nb;
 
 /// Full duplex (master mode)
@@ -12,6 +13,7 @@ use nb;
 ///
 /// - Some SPIs can work with 8-bit *and* 16-bit words. You can overload this trait with different
 /// `Word` types to allow operation in both modes.
+#[cfg(feature = "nb")]
 pub trait FullDuplex<Word> {
     /// An enumeration of SPI errors
     type Error;
@@ -26,6 +28,22 @@ pub trait FullDuplex<Word> {
     fn try_send(&mut self, word: Word) -> nb::Result<(), Self::Error>;
 }
 
+#[cfg(feature = "nb")]
+impl<T, Word> FullDuplex<Word> for &mut T
+where
+    T: FullDuplex<Word> + ?Sized,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        T::try_read(self)
+    }
+
+    fn try_send(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        T::try_send(self, word)
+    }
+}
+
 /// Clock polarity
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Polarity {
@@ -0,0 +1,77 @@
+//! Synchronous Serial Interface (SSI) absolute encoders
+//!
+//! SSI encoders shift their absolute position out, MSB first, on a clock generated by the master.
+//! The frame length and the coding of the position (binary or Gray code) depend on the encoder.
+
+/// Coding of the position in an SSI frame
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Coding {
+    /// Plain binary
+    Binary,
+    /// Gray code
+    Gray,
+}
+
+/// Converts a Gray-coded value to binary
+///
+/// ```
+/// use embedded_hal::ssi::gray_to_binary;
+///
+/// assert_eq!(gray_to_binary(0b000), 0);
+/// assert_eq!(gray_to_binary(0b110), 4);
+/// assert_eq!(gray_to_binary(0b100), 7);
+/// ```
+pub fn gray_to_binary(gray: u32) -> u32 {
+    let mut binary = gray;
+    let mut shift = 1;
+    while shift < 32 {
+        binary ^= binary >> shift;
+        shift <<= 1;
+    }
+    binary
+}
+
+/// SSI master reading an absolute encoder
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::ssi::{Coding, Ssi};
+///
+/// fn angle<S: Ssi>(encoder: &mut S) -> Result<u32, S::Error> {
+///     // 13-bit single-turn encoder, Gray coded
+///     encoder.try_set_frame_bits(13)?;
+///     encoder.try_read_position(Coding::Gray)
+/// }
+/// ```
+pub trait Ssi {
+    /// An enumeration of `Ssi` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Sets the number of bits per frame
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `bits` is zero or greater than 32.
+    fn try_set_frame_bits(&mut self, bits: u8) -> Result<(), Self::Error>;
+
+    /// Clocks a frame out of the encoder and returns it, right-aligned
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the data line doesn't behave as expected, e.g. when the
+    /// encoder is disconnected.
+    fn try_read_frame(&mut self) -> Result<u32, Self::Error>;
+
+    /// Reads a frame and decodes it as a position
+    fn try_read_position(&mut self, coding: Coding) -> Result<u32, Self::Error> {
+        let frame = self.try_read_frame()?;
+
+        Ok(match coding {
+            Coding::Binary => frame,
+            Coding::Gray => gray_to_binary(frame),
+        })
+    }
+}
@@ -0,0 +1,243 @@
+//! Bridges between the serial traits and `std::io`
+//!
+//! *This module is available if embedded-hal is built with the `"std"` feature.*
+//!
+//! [`Serial`] exposes a `std::io` stream, e.g. a TCP socket or a PTY, through the serial
+//! traits, so drivers can be exercised on a development host. [`Stream`] goes the other way and
+//! exposes a serial interface as a `std::io` stream.
+//!
+//! [`Serial`]: struct.Serial.html
+//! [`Stream`]: struct.Stream.html
+
+use std::fmt::Debug;
+use std::format;
+use std::io;
+
+use crate::blocking;
+use crate::serial;
+
+/// Serial interface backed by a `std::io` stream
+///
+/// `WouldBlock` I/O errors, as returned by non-blocking sockets, are reported as
+/// `nb::Error::WouldBlock`. Reaching the end of the stream is reported as an
+/// `UnexpectedEof` error.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::blocking::serial::Write;
+/// use embedded_hal::serial::Read;
+/// use embedded_hal::std_io::Serial;
+/// use std::io::Cursor;
+///
+/// let mut serial = Serial::new(Cursor::new(vec![b'O', b'K']));
+/// assert_eq!(serial.try_read().unwrap(), b'O');
+/// assert_eq!(serial.try_read().unwrap(), b'K');
+/// assert!(serial.try_read().is_err());
+///
+/// let mut serial = Serial::new(Vec::new());
+/// serial.try_bwrite_all(b"AT\r\n").unwrap();
+/// assert_eq!(serial.free(), b"AT\r\n");
+/// ```
+pub struct Serial<T> {
+    stream: T,
+}
+
+impl<T> Serial<T> {
+    /// Wraps `stream`
+    pub fn new(stream: T) -> Self {
+        Serial { stream }
+    }
+
+    /// Releases the stream
+    pub fn free(self) -> T {
+        self.stream
+    }
+}
+
+fn nb_error(e: io::Error) -> nb::Error<io::Error> {
+    if e.kind() == io::ErrorKind::WouldBlock {
+        nb::Error::WouldBlock
+    } else {
+        nb::Error::Other(e)
+    }
+}
+
+impl<T> serial::Read<u8> for Serial<T>
+where
+    T: io::Read,
+{
+    type Error = io::Error;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        let mut word = [0];
+        match self.stream.read(&mut word) {
+            Ok(0) => Err(nb::Error::Other(io::ErrorKind::UnexpectedEof.into())),
+            Ok(_) => Ok(word[0]),
+            Err(e) => Err(nb_error(e)),
+        }
+    }
+}
+
+impl<T> serial::Write<u8> for Serial<T>
+where
+    T: io::Write,
+{
+    type Error = io::Error;
+
+    fn try_write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        match self.stream.write(&[word]) {
+            Ok(0) => Err(nb::Error::Other(io::ErrorKind::WriteZero.into())),
+            Ok(_) => Ok(()),
+            Err(e) => Err(nb_error(e)),
+        }
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.stream.flush().map_err(nb_error)
+    }
+}
+
+impl<T> blocking::serial::Write<u8> for Serial<T>
+where
+    T: io::Write,
+{
+    type Error = io::Error;
+
+    fn try_bwrite_all(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        for &word in buffer {
+            block!(serial::Write::try_write(self, word))?;
+        }
+
+        Ok(())
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        block!(serial::Write::try_flush(self))
+    }
+}
+
+/// `std::io` stream backed by a serial interface
+///
+/// `read` blocks until at least one word is available, then returns the words received
+/// without blocking further. `write` blocks until at least one word is sent. Serial errors are
+/// reported as I/O errors of kind `Other`; an error hit after some words were transferred is
+/// reported by the next call instead, so the count of the words transferred isn't lost.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::std_io::Stream;
+/// use std::io::{BufRead, BufReader};
+/// # use std::convert::Infallible;
+/// # struct Serial(&'static [u8]);
+/// # impl embedded_hal::serial::Read<u8> for Serial {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self) -> nb::Result<u8, Infallible> {
+/// #         let (&word, rest) = self.0.split_first().ok_or(nb::Error::WouldBlock)?;
+/// #         self.0 = rest;
+/// #         Ok(word)
+/// #     }
+/// # }
+///
+/// let serial = Serial(b"+OK\r\n");
+///
+/// let mut line = String::new();
+/// BufReader::new(Stream::new(serial)).read_line(&mut line).unwrap();
+/// assert_eq!(line, "+OK\r\n");
+/// ```
+pub struct Stream<T> {
+    serial: T,
+    read_error: Option<io::Error>,
+    write_error: Option<io::Error>,
+}
+
+impl<T> Stream<T> {
+    /// Wraps `serial`
+    pub fn new(serial: T) -> Self {
+        Stream {
+            serial,
+            read_error: None,
+            write_error: None,
+        }
+    }
+
+    /// Releases the serial interface
+    pub fn free(self) -> T {
+        self.serial
+    }
+}
+
+const SERIAL_ERROR: io::ErrorKind = io::ErrorKind::Other;
+
+fn io_error<E>(e: E) -> io::Error
+where
+    E: Debug,
+{
+    io::Error::new(SERIAL_ERROR, format!("{:?}", e))
+}
+
+impl<T> io::Read for Stream<T>
+where
+    T: serial::Read<u8>,
+    T::Error: Debug,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(e) = self.read_error.take() {
+            return Err(e);
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = block!(self.serial.try_read()).map_err(io_error)?;
+        for (i, word) in buf.iter_mut().enumerate().skip(1) {
+            match self.serial.try_read() {
+                Ok(w) => *word = w,
+                Err(nb::Error::WouldBlock) => return Ok(i),
+                Err(nb::Error::Other(e)) => {
+                    self.read_error = Some(io_error(e));
+                    return Ok(i);
+                }
+            }
+        }
+
+        Ok(buf.len())
+    }
+}
+
+impl<T> io::Write for Stream<T>
+where
+    T: serial::Write<u8>,
+    T::Error: Debug,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(e) = self.write_error.take() {
+            return Err(e);
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        block!(self.serial.try_write(buf[0])).map_err(io_error)?;
+        for (i, &word) in buf.iter().enumerate().skip(1) {
+            match self.serial.try_write(word) {
+                Ok(()) => {}
+                Err(nb::Error::WouldBlock) => return Ok(i),
+                Err(nb::Error::Other(e)) => {
+                    self.write_error = Some(io_error(e));
+                    return Ok(i);
+                }
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(e) = self.write_error.take() {
+            return Err(e);
+        }
+        block!(self.serial.try_flush()).map_err(io_error)
+    }
+}
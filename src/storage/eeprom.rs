@@ -0,0 +1,26 @@
+//! EEPROM memories
+//!
+//! EEPROMs can be read and written byte by byte; an EEPROM takes care of erasing the bytes it
+//! programs.
+
+/// EEPROM
+pub trait Eeprom {
+    /// An enumeration of EEPROM errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of bounds*, the operation goes past the end of the EEPROM
+    type Error;
+
+    /// Reads `bytes.len()` bytes starting at `offset`
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Writes `bytes` starting at `offset`
+    ///
+    /// Implementations split the write along the page boundaries of the device and wait for
+    /// each page to be programmed.
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error>;
+
+    /// Returns the size of the EEPROM, in bytes
+    fn capacity(&self) -> usize;
+}
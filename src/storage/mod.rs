@@ -0,0 +1,265 @@
+//! Non-volatile storage
+//!
+//! [`Storage`] is byte-addressable storage: any range of bytes can be read or overwritten.
+//! Configuration and blob storage libraries only need this bound, whatever the memory behind it:
+//!
+//! - Every [`Eeprom`] implements `Storage`.
+//! - [`NorFlashStorage`] implements `Storage` on top of a [`NorFlash`], erasing and rewriting the
+//!   sectors touched by each write.
+//!
+//! One-time programmable memories are covered by the [`otp`] module.
+//!
+//! [`Storage`]: trait.Storage.html
+//! [`Eeprom`]: eeprom/trait.Eeprom.html
+//! [`NorFlashStorage`]: struct.NorFlashStorage.html
+//! [`NorFlash`]: nor_flash/trait.NorFlash.html
+//! [`otp`]: otp/index.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::storage::Storage;
+//!
+//! const CONFIG: u32 = 0;
+//!
+//! fn save_baud_rate<S: Storage>(storage: &mut S, baud_rate: u32) -> Result<(), S::Error> {
+//!     storage.try_write(CONFIG, &baud_rate.to_le_bytes())
+//! }
+//!
+//! fn load_baud_rate<S: Storage>(storage: &mut S) -> Result<u32, S::Error> {
+//!     let mut bytes = [0; 4];
+//!     storage.try_read(CONFIG, &mut bytes)?;
+//!     Ok(u32::from_le_bytes(bytes))
+//! }
+//! ```
+
+pub mod eeprom;
+pub mod nor_flash;
+pub mod otp;
+
+use self::eeprom::Eeprom;
+use self::nor_flash::NorFlash;
+
+/// Read only byte-addressable storage
+pub trait ReadStorage {
+    /// An enumeration of storage errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of bounds*, the operation goes past the end of the storage
+    type Error;
+
+    /// Reads `bytes.len()` bytes starting at `offset`
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Returns the size of the storage, in bytes
+    fn capacity(&self) -> usize;
+}
+
+/// Byte-addressable storage
+pub trait Storage: ReadStorage {
+    /// Writes `bytes` starting at `offset`
+    ///
+    /// The bytes outside of the written range are preserved.
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<T> ReadStorage for T
+where
+    T: Eeprom,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        Eeprom::try_read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        Eeprom::capacity(self)
+    }
+}
+
+impl<T> Storage for T
+where
+    T: Eeprom,
+{
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        Eeprom::try_write(self, offset, bytes)
+    }
+}
+
+/// Byte-addressable storage on top of a NOR flash
+///
+/// Each write reads the sectors it touches into a merge buffer and applies the new bytes. If the
+/// write only clears bits, only the `WRITE_SIZE` words that changed are programmed; otherwise the
+/// sector is erased and programmed back whole.
+///
+/// The merge buffer must hold a whole erase sector, which is expensive on devices with large
+/// sectors, e.g. 128 KiB for the main sectors of an STM32F4. Prefer a flash-aware storage layer
+/// if the RAM can't be spared.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::storage::{NorFlashStorage, ReadStorage, Storage};
+/// # use embedded_hal::storage::nor_flash::{NorFlash, ReadNorFlash};
+/// # use core::convert::Infallible;
+/// # struct Flash([u8; 4096]);
+/// # impl ReadNorFlash for Flash {
+/// #     type Error = Infallible;
+/// #     const READ_SIZE: usize = 1;
+/// #     fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Infallible> {
+/// #         let offset = offset as usize;
+/// #         bytes.copy_from_slice(&self.0[offset..offset + bytes.len()]);
+/// #         Ok(())
+/// #     }
+/// #     fn capacity(&self) -> usize { self.0.len() }
+/// # }
+/// # impl NorFlash for Flash {
+/// #     const WRITE_SIZE: usize = 4;
+/// #     const ERASE_SIZE: usize = 1024;
+/// #     fn try_erase(&mut self, from: u32, to: u32) -> Result<(), Infallible> {
+/// #         self.0[from as usize..to as usize].iter_mut().for_each(|b| *b = 0xFF);
+/// #         Ok(())
+/// #     }
+/// #     fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Infallible> {
+/// #         let offset = offset as usize;
+/// #         self.0[offset..offset + bytes.len()].iter_mut().zip(bytes).for_each(|(b, n)| *b &= n);
+/// #         Ok(())
+/// #     }
+/// # }
+///
+/// let mut buffer = [0; 1024];
+/// let mut storage = NorFlashStorage::new(Flash([0xFF; 4096]), &mut buffer);
+///
+/// storage.try_write(1020, b"Hello, world!").unwrap();
+/// storage.try_write(1027, b"flash").unwrap();
+///
+/// let mut bytes = [0; 13];
+/// storage.try_read(1020, &mut bytes).unwrap();
+/// assert_eq!(&bytes, b"Hello, flash!");
+/// ```
+pub struct NorFlashStorage<'a, F> {
+    flash: F,
+    buffer: &'a mut [u8],
+}
+
+impl<'a, F> NorFlashStorage<'a, F>
+where
+    F: NorFlash,
+{
+    /// Wraps `flash`, using `buffer` to merge the sectors being written
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is smaller than an erase sector.
+    pub fn new(flash: F, buffer: &'a mut [u8]) -> Self {
+        assert!(buffer.len() >= F::ERASE_SIZE);
+
+        NorFlashStorage { flash, buffer }
+    }
+
+    /// Releases the flash
+    pub fn free(self) -> F {
+        self.flash
+    }
+}
+
+impl<'a, F> ReadStorage for NorFlashStorage<'a, F>
+where
+    F: NorFlash,
+{
+    type Error = F::Error;
+
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let aligned = |n: usize| n / F::READ_SIZE * F::READ_SIZE == n;
+        if aligned(offset as usize) && aligned(bytes.len()) {
+            return self.flash.try_read(offset, bytes);
+        }
+
+        // unaligned read: go through the merge buffer one sector at a time
+        let mut offset = offset;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let sector = offset - offset % F::ERASE_SIZE as u32;
+            let start = (offset - sector) as usize;
+            let len = core::cmp::min(bytes.len(), F::ERASE_SIZE - start);
+
+            let buffer = &mut self.buffer[..F::ERASE_SIZE];
+            self.flash.try_read(sector, buffer)?;
+
+            let (head, tail) = bytes.split_at_mut(len);
+            head.copy_from_slice(&buffer[start..start + len]);
+            bytes = tail;
+            offset += len as u32;
+        }
+
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.flash.capacity()
+    }
+}
+
+impl<'a, F> Storage for NorFlashStorage<'a, F>
+where
+    F: NorFlash,
+{
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let mut offset = offset;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let sector = offset - offset % F::ERASE_SIZE as u32;
+            let start = (offset - sector) as usize;
+            let len = core::cmp::min(bytes.len(), F::ERASE_SIZE - start);
+            let (head, tail) = bytes.split_at(len);
+
+            let buffer = &mut self.buffer[..F::ERASE_SIZE];
+            self.flash.try_read(sector, buffer)?;
+
+            let current = &buffer[start..start + len];
+            if current != head {
+                // programming can only clear bits
+                let needs_erase = current
+                    .iter()
+                    .zip(head)
+                    .any(|(&old, &new)| old & new != new);
+
+                if needs_erase {
+                    buffer[start..start + len].copy_from_slice(head);
+                    self.flash
+                        .try_erase(sector, sector + F::ERASE_SIZE as u32)?;
+                    self.flash.try_write(sector, buffer)?;
+                } else {
+                    // program the runs of words that changed, leaving the others untouched
+                    let first = start / F::WRITE_SIZE * F::WRITE_SIZE;
+                    let end = (start + len - 1) / F::WRITE_SIZE * F::WRITE_SIZE + F::WRITE_SIZE;
+                    let mut run = None;
+                    for word in (first..end).step_by(F::WRITE_SIZE) {
+                        let from = core::cmp::max(word, start);
+                        let to = core::cmp::min(word + F::WRITE_SIZE, start + len);
+                        let new = &head[from - start..to - start];
+
+                        if buffer[from..to] != *new {
+                            buffer[from..to].copy_from_slice(new);
+                            run = run.or(Some(word));
+                        } else if let Some(run_start) = run.take() {
+                            self.flash
+                                .try_write(sector + run_start as u32, &buffer[run_start..word])?;
+                        }
+                    }
+                    if let Some(run_start) = run {
+                        self.flash
+                            .try_write(sector + run_start as u32, &buffer[run_start..end])?;
+                    }
+                }
+            }
+
+            bytes = tail;
+            offset += len as u32;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+//! NOR flash memories
+//!
+//! NOR flash is read byte by byte, but programming can only clear bits and works on `WRITE_SIZE`
+//! aligned words; bits are set back to `1` by erasing whole `ERASE_SIZE` aligned sectors.
+
+/// Read only NOR flash
+pub trait ReadNorFlash {
+    /// An enumeration of flash errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of bounds*, the operation goes past the end of the flash
+    /// - *not aligned*, the offset or length is not a multiple of the required alignment
+    type Error;
+
+    /// The minimum number of bytes the flash can read, and the alignment of reads
+    const READ_SIZE: usize;
+
+    /// Reads `bytes.len()` bytes starting at `offset`
+    fn try_read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Returns the size of the flash, in bytes
+    fn capacity(&self) -> usize;
+}
+
+/// NOR flash
+pub trait NorFlash: ReadNorFlash {
+    /// The minimum number of bytes the flash can program, and the alignment of writes
+    const WRITE_SIZE: usize;
+
+    /// The size of an erase sector, and the alignment of erases
+    const ERASE_SIZE: usize;
+
+    /// Erases the sectors between `from` (inclusive) and `to` (exclusive), setting all their bits
+    /// to `1`
+    fn try_erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error>;
+
+    /// Programs `bytes` starting at `offset`
+    ///
+    /// Only bits that are `1` can be cleared to `0`; programming a `1` leaves the bit unchanged.
+    fn try_write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,66 @@
+//! One-time programmable memories
+//!
+//! OTP memories and eFuses are made of words whose bits can be burnt once: a burnt bit moves away
+//! from its blank value and can never go back. They hold device-unique data written during
+//! provisioning, e.g. serial numbers, calibration values, keys or boot configuration.
+//!
+//! Depending on the technology, a blank bit reads as `0` (most eFuses) or `1` (flash based OTP
+//! areas, e.g. on STM32); [`Otp::BLANK`] tells which.
+//!
+//! [`Otp::BLANK`]: trait.Otp.html#associatedconstant.BLANK
+
+/// One-time programmable memory
+///
+/// # Examples
+///
+/// Write a serial number during provisioning, unless one was already written
+///
+/// ```
+/// use embedded_hal::storage::otp::Otp;
+///
+/// const SERIAL_NUMBER: usize = 0;
+///
+/// fn provision<O: Otp<Word = u32>>(otp: &mut O, serial_number: u32) -> Result<bool, O::Error> {
+///     if otp.try_read(SERIAL_NUMBER)? != O::BLANK {
+///         return Ok(false);
+///     }
+///
+///     otp.try_program_irreversible(SERIAL_NUMBER, serial_number)?;
+///     Ok(otp.try_read(SERIAL_NUMBER)? == serial_number)
+/// }
+/// ```
+pub trait Otp {
+    /// An enumeration of OTP errors
+    ///
+    /// Possible errors:
+    ///
+    /// - *out of bounds*, `index` is past the last word
+    /// - *locked*, the word has been write-protected
+    /// - *program failure*, the word read back doesn't match after programming
+    type Error;
+
+    /// The unit of programming, e.g. `u32`
+    type Word;
+
+    /// The value of a word whose bits are all blank, e.g. `0` or `0xFFFF_FFFF`
+    const BLANK: Self::Word;
+
+    /// Returns the number of words of the memory
+    fn word_count(&self) -> usize;
+
+    /// Reads the word at `index`
+    fn try_read(&mut self, index: usize) -> Result<Self::Word, Self::Error>;
+
+    /// Burns the bits of `word` that differ from [`BLANK`] into the word at `index`
+    ///
+    /// **This can't be undone.** Bits move away from their blank value, never back: the word then
+    /// reads as its previous value ORed with `word` if `BLANK` is `0`, ANDed with `word` if
+    /// `BLANK` is all ones. Implementations don't reprogram bits that are already burnt.
+    ///
+    /// [`BLANK`]: #associatedconstant.BLANK
+    fn try_program_irreversible(
+        &mut self,
+        index: usize,
+        word: Self::Word,
+    ) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,207 @@
+//! Generic checks of trait implementations
+//!
+//! These functions exercise the semantics documented by the traits of this crate. HAL
+//! implementers can run them on hardware, e.g. from a test firmware, or against simulated
+//! peripherals in CI. Each check returns `Ok(())` if the implementation behaved as expected.
+//!
+//! Some checks need external wiring, which is documented on the function.
+
+use crate::blocking::spi::Transfer;
+use crate::digital::{InputPin, OutputPin, StatefulOutputPin, ToggleableOutputPin};
+use crate::serial;
+
+/// Result of a check
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error<E> {
+    /// The implementation returned an error
+    Hal(E),
+    /// The implementation didn't behave as expected; the message describes the violation
+    Failed(&'static str),
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(e: E) -> Self {
+        Error::Hal(e)
+    }
+}
+
+fn ensure<E>(condition: bool, message: &'static str) -> Result<(), Error<E>> {
+    if condition {
+        Ok(())
+    } else {
+        Err(Error::Failed(message))
+    }
+}
+
+/// Checks that an output pin reads back the level it drives
+///
+/// The pin must be left floating or only lightly loaded.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::testsuite;
+/// # use core::{cell::Cell, convert::Infallible};
+/// # struct Pin(Cell<bool>);
+/// # impl embedded_hal::digital::OutputPin for Pin {
+/// #     type Error = Infallible;
+/// #     fn try_set_low(&mut self) -> Result<(), Infallible> { self.0.set(false); Ok(()) }
+/// #     fn try_set_high(&mut self) -> Result<(), Infallible> { self.0.set(true); Ok(()) }
+/// # }
+/// # impl embedded_hal::digital::InputPin for Pin {
+/// #     type Error = Infallible;
+/// #     fn try_is_high(&self) -> Result<bool, Infallible> { Ok(self.0.get()) }
+/// #     fn try_is_low(&self) -> Result<bool, Infallible> { Ok(!self.0.get()) }
+/// # }
+///
+/// let mut pin = Pin(Cell::new(false));
+///
+/// testsuite::check_output_pin(&mut pin).unwrap();
+/// ```
+pub fn check_output_pin<P, E>(pin: &mut P) -> Result<(), Error<E>>
+where
+    P: OutputPin<Error = E> + InputPin<Error = E>,
+{
+    pin.try_set_high()?;
+    ensure(
+        pin.try_is_high()?,
+        "pin doesn't read high after `try_set_high`",
+    )?;
+    ensure(!pin.try_is_low()?, "pin reads both high and low")?;
+
+    pin.try_set_low()?;
+    ensure(
+        pin.try_is_low()?,
+        "pin doesn't read low after `try_set_low`",
+    )?;
+    ensure(!pin.try_is_high()?, "pin reads both high and low")
+}
+
+/// Checks that a stateful output pin reports the level it was set to
+pub fn check_stateful_output_pin<P>(pin: &mut P) -> Result<(), Error<P::Error>>
+where
+    P: StatefulOutputPin,
+{
+    pin.try_set_high()?;
+    ensure(
+        pin.try_is_set_high()?,
+        "pin isn't set high after `try_set_high`",
+    )?;
+    ensure(!pin.try_is_set_low()?, "pin is set both high and low")?;
+
+    pin.try_set_low()?;
+    ensure(
+        pin.try_is_set_low()?,
+        "pin isn't set low after `try_set_low`",
+    )?;
+    ensure(!pin.try_is_set_high()?, "pin is set both high and low")
+}
+
+/// Checks that toggling a stateful output pin inverts its level, twice
+pub fn check_toggleable_output_pin<P, E>(pin: &mut P) -> Result<(), Error<E>>
+where
+    P: StatefulOutputPin<Error = E> + ToggleableOutputPin<Error = E>,
+{
+    pin.try_set_low()?;
+    pin.try_toggle()?;
+    ensure(
+        pin.try_is_set_high()?,
+        "pin isn't set high after toggling from low",
+    )?;
+    pin.try_toggle()?;
+    ensure(
+        pin.try_is_set_low()?,
+        "pin isn't set low after toggling from high",
+    )
+}
+
+/// Checks that every word written to a serial interface is read back
+///
+/// TX must be wired to RX. Words are written and read one at a time, so the check doesn't
+/// depend on the depth of the receive FIFO.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::testsuite;
+/// # use core::convert::Infallible;
+/// # struct Serial(Option<u8>);
+/// # impl embedded_hal::serial::Write<u8> for Serial {
+/// #     type Error = Infallible;
+/// #     fn try_write(&mut self, w: u8) -> nb::Result<(), Infallible> { self.0 = Some(w); Ok(()) }
+/// #     fn try_flush(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// # impl embedded_hal::serial::Read<u8> for Serial {
+/// #     type Error = Infallible;
+/// #     fn try_read(&mut self) -> nb::Result<u8, Infallible> {
+/// #         self.0.take().ok_or(nb::Error::WouldBlock)
+/// #     }
+/// # }
+///
+/// let mut serial = Serial(None);
+///
+/// testsuite::check_serial_loopback(&mut serial, b"loopback").unwrap();
+/// ```
+pub fn check_serial_loopback<S, E>(serial: &mut S, data: &[u8]) -> Result<(), Error<E>>
+where
+    S: serial::Read<u8, Error = E> + serial::Write<u8, Error = E>,
+{
+    for &word in data {
+        block!(serial.try_write(word))?;
+        block!(serial.try_flush())?;
+        ensure(
+            block!(serial.try_read())? == word,
+            "word read back differs from the word written",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Checks that every word transferred on an SPI bus is read back
+///
+/// MOSI must be wired to MISO. `buffer` is filled with a test pattern and transferred.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::testsuite;
+/// # use core::convert::Infallible;
+/// # struct Spi;
+/// # impl embedded_hal::blocking::spi::Transfer<u8> for Spi {
+/// #     type Error = Infallible;
+/// #     fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Infallible> {
+/// #         Ok(words)
+/// #     }
+/// # }
+///
+/// let mut spi = Spi;
+///
+/// testsuite::check_spi_loopback(&mut spi, &mut [0; 16]).unwrap();
+/// ```
+pub fn check_spi_loopback<S>(spi: &mut S, buffer: &mut [u8]) -> Result<(), Error<S::Error>>
+where
+    S: Transfer<u8>,
+{
+    for (i, word) in buffer.iter_mut().enumerate() {
+        *word = pattern(i);
+    }
+
+    let len = buffer.len();
+    let received = spi.try_transfer(buffer)?;
+    ensure(
+        received.len() == len,
+        "transfer returned a different number of words",
+    )?;
+    ensure(
+        received
+            .iter()
+            .enumerate()
+            .all(|(i, &word)| word == pattern(i)),
+        "words read back differ from the words written",
+    )
+}
+
+fn pattern(i: usize) -> u8 {
+    (i as u8) ^ 0xA5
+}
@@ -0,0 +1,373 @@
+//! Time units
+//!
+//! Crate-wide duration and frequency types. HALs are encouraged to use them as the `Time`
+//! associated type of their traits (or to accept them through `Into`) so that drivers can express
+//! time portably, e.g. `timer.try_start(100.millis())`.
+//!
+//! The [`Nanoseconds`], [`Microseconds`], [`Milliseconds`] and [`Seconds`] unit types carry their
+//! unit in the type system: they all convert into a [`Duration`], but passing microseconds where
+//! e.g. a `Milliseconds` value is expected is a compile error rather than a 1000x timing bug.
+//! Likewise, [`Hertz`], [`KiloHertz`] and [`MegaHertz`] convert into a [`Rate`]. Values of the
+//! same unit can be added, subtracted, and multiplied or divided by a `u32`. Unit types also
+//! convert into the finer units of the same quantity with `TryFrom`, e.g. [`KiloHertz`] into
+//! [`Hertz`], failing with [`Overflow`] if the result doesn't fit in a `u32`.
+//!
+//! [`Nanoseconds`]: struct.Nanoseconds.html
+//! [`Microseconds`]: struct.Microseconds.html
+//! [`Milliseconds`]: struct.Milliseconds.html
+//! [`Seconds`]: struct.Seconds.html
+//! [`Duration`]: struct.Duration.html
+//! [`Hertz`]: struct.Hertz.html
+//! [`KiloHertz`]: struct.KiloHertz.html
+//! [`MegaHertz`]: struct.MegaHertz.html
+//! [`Rate`]: struct.Rate.html
+//! [`Overflow`]: struct.Overflow.html
+//!
+//! ```
+//! use core::convert::TryFrom;
+//! use embedded_hal::time::{
+//!     Duration, Hertz, Microseconds, Milliseconds, Nanoseconds, Overflow, Rate, U32Ext,
+//! };
+//!
+//! assert_eq!(100.millis(), Milliseconds(100));
+//! assert_eq!(Duration::from(100.millis()), Duration::from_micros(100_000));
+//! assert_eq!(Rate::from(1.khz()).period(), 1.millis().into());
+//! assert_eq!(Rate::from(8.mhz()).as_hz(), 8_000_000);
+//!
+//! let timeout = 10.millis() * 3 + 5.millis();
+//! assert_eq!(timeout, Milliseconds(35));
+//! assert_eq!(Hertz::try_from(2.khz()).unwrap() / 4, 500.hz());
+//! assert_eq!(Microseconds::try_from(3.millis()), Ok(3_000.micros()));
+//!
+//! assert_eq!(Hertz::try_from(4_294_967.khz()), Ok(4_294_967_000.hz()));
+//! assert_eq!(Hertz::try_from(4_294_968.khz()), Err(Overflow));
+//! assert_eq!(Nanoseconds::try_from(4.secs()), Ok(4_000_000_000.nanos()));
+//! assert_eq!(Nanoseconds::try_from(5.secs()), Err(Overflow));
+//! ```
+
+use core::convert::{identity, TryFrom};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/// A span of time, with nanosecond resolution
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Duration {
+    nanos: u64,
+}
+
+impl Duration {
+    /// Creates a `Duration` from a number of nanoseconds
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Duration { nanos }
+    }
+
+    /// Creates a `Duration` from a number of microseconds
+    pub const fn from_micros(micros: u64) -> Self {
+        Duration {
+            nanos: micros * 1_000,
+        }
+    }
+
+    /// Creates a `Duration` from a number of milliseconds
+    pub const fn from_millis(millis: u64) -> Self {
+        Duration {
+            nanos: millis * 1_000_000,
+        }
+    }
+
+    /// Creates a `Duration` from a number of seconds
+    pub const fn from_secs(secs: u64) -> Self {
+        Duration {
+            nanos: secs * 1_000_000_000,
+        }
+    }
+
+    /// Returns the total number of whole nanoseconds
+    pub const fn as_nanos(&self) -> u64 {
+        self.nanos
+    }
+
+    /// Returns the total number of whole microseconds
+    pub const fn as_micros(&self) -> u64 {
+        self.nanos / 1_000
+    }
+
+    /// Returns the total number of whole milliseconds
+    pub const fn as_millis(&self) -> u64 {
+        self.nanos / 1_000_000
+    }
+
+    /// Returns the total number of whole seconds
+    pub const fn as_secs(&self) -> u64 {
+        self.nanos / 1_000_000_000
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Duration {
+        Duration {
+            nanos: self.nanos + rhs.nanos,
+        }
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Duration) {
+        self.nanos += rhs.nanos;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Duration) -> Duration {
+        Duration {
+            nanos: self.nanos - rhs.nanos,
+        }
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.nanos -= rhs.nanos;
+    }
+}
+
+macro_rules! unit {
+    ($(#[$attr:meta])* $name:ident, $from:ident) => {
+        unit!($(#[$attr])* $name, Duration, $from, u64::from);
+    };
+    ($(#[$attr:meta])* $name:ident, $into:ident, $from:ident, $convert:path) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $name(pub u32);
+
+        impl From<$name> for $into {
+            fn from(value: $name) -> $into {
+                $into::$from($convert(value.0))
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Mul<u32> for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: u32) -> $name {
+                $name(self.0 * rhs)
+            }
+        }
+
+        impl Div<u32> for $name {
+            type Output = $name;
+
+            fn div(self, rhs: u32) -> $name {
+                $name(self.0 / rhs)
+            }
+        }
+    };
+}
+
+unit!(
+    /// A number of nanoseconds
+    Nanoseconds,
+    from_nanos
+);
+
+unit!(
+    /// A number of microseconds
+    Microseconds,
+    from_micros
+);
+
+unit!(
+    /// A number of milliseconds
+    Milliseconds,
+    from_millis
+);
+
+unit!(
+    /// A number of seconds
+    Seconds,
+    from_secs
+);
+
+/// A frequency, with hertz resolution
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rate {
+    hz: u32,
+}
+
+impl Rate {
+    /// Creates a `Rate` from a number of hertz
+    pub const fn from_hz(hz: u32) -> Self {
+        Rate { hz }
+    }
+
+    /// Creates a `Rate` from a number of kilohertz
+    pub const fn from_khz(khz: u32) -> Self {
+        Rate { hz: khz * 1_000 }
+    }
+
+    /// Creates a `Rate` from a number of megahertz
+    pub const fn from_mhz(mhz: u32) -> Self {
+        Rate {
+            hz: mhz * 1_000_000,
+        }
+    }
+
+    /// Returns the frequency in hertz
+    pub const fn as_hz(&self) -> u32 {
+        self.hz
+    }
+
+    /// Returns the period of this frequency, rounded down to the nanosecond
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency is zero.
+    pub fn period(&self) -> Duration {
+        Duration::from_nanos(1_000_000_000 / u64::from(self.hz))
+    }
+}
+
+unit!(
+    /// A number of hertz
+    Hertz,
+    Rate,
+    from_hz,
+    identity
+);
+
+unit!(
+    /// A number of kilohertz
+    KiloHertz,
+    Rate,
+    from_khz,
+    identity
+);
+
+unit!(
+    /// A number of megahertz
+    MegaHertz,
+    Rate,
+    from_mhz,
+    identity
+);
+
+impl From<Rate> for Hertz {
+    fn from(rate: Rate) -> Hertz {
+        Hertz(rate.hz)
+    }
+}
+
+/// Error of a unit conversion whose result doesn't fit in a `u32`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Overflow;
+
+macro_rules! widen {
+    ($($from:ident => $into:ident * $factor:expr),* $(,)?) => {
+        $(
+            impl TryFrom<$from> for $into {
+                type Error = Overflow;
+
+                fn try_from(value: $from) -> Result<$into, Overflow> {
+                    value.0.checked_mul($factor).map($into).ok_or(Overflow)
+                }
+            }
+        )*
+    };
+}
+
+widen!(
+    Seconds => Milliseconds * 1_000,
+    Seconds => Microseconds * 1_000_000,
+    Seconds => Nanoseconds * 1_000_000_000,
+    Milliseconds => Microseconds * 1_000,
+    Milliseconds => Nanoseconds * 1_000_000,
+    Microseconds => Nanoseconds * 1_000,
+    MegaHertz => KiloHertz * 1_000,
+    MegaHertz => Hertz * 1_000_000,
+    KiloHertz => Hertz * 1_000,
+);
+
+/// Extension trait that adds convenience methods to the `u32` type
+pub trait U32Ext {
+    /// Wraps in `Nanoseconds`
+    fn nanos(self) -> Nanoseconds;
+
+    /// Wraps in `Microseconds`
+    fn micros(self) -> Microseconds;
+
+    /// Wraps in `Milliseconds`
+    fn millis(self) -> Milliseconds;
+
+    /// Wraps in `Seconds`
+    fn secs(self) -> Seconds;
+
+    /// Wraps in `Hertz`
+    fn hz(self) -> Hertz;
+
+    /// Wraps in `KiloHertz`
+    fn khz(self) -> KiloHertz;
+
+    /// Wraps in `MegaHertz`
+    fn mhz(self) -> MegaHertz;
+}
+
+impl U32Ext for u32 {
+    fn nanos(self) -> Nanoseconds {
+        Nanoseconds(self)
+    }
+
+    fn micros(self) -> Microseconds {
+        Microseconds(self)
+    }
+
+    fn millis(self) -> Milliseconds {
+        Milliseconds(self)
+    }
+
+    fn secs(self) -> Seconds {
+        Seconds(self)
+    }
+
+    fn hz(self) -> Hertz {
+        Hertz(self)
+    }
+
+    fn khz(self) -> KiloHertz {
+        KiloHertz(self)
+    }
+
+    fn mhz(self) -> MegaHertz {
+        MegaHertz(self)
+    }
+}
@@ -1,5 +1,8 @@
 //! Timers
 
+use core::ops::{Add, Sub};
+
+#[cfg(feature = "nb")]
 use nb;
 
 /// A count down timer
@@ -34,15 +37,13 @@ use nb;
 ///     };
 ///
 ///     Led.on();
-///     timer.try_start(1.s()).unwrap();
+///     timer.try_start(1.secs()).unwrap();
 ///     block!(timer.try_wait()); // blocks for 1 second
 ///     Led.off();
 /// }
 ///
 /// # use core::convert::Infallible;
-/// # struct Seconds(u32);
-/// # trait U32Ext { fn s(self) -> Seconds; }
-/// # impl U32Ext for u32 { fn s(self) -> Seconds { Seconds(self) } }
+/// # use hal::time::Duration;
 /// # struct Led;
 /// # impl Led {
 /// #     pub fn off(&mut self) {}
@@ -51,11 +52,12 @@ use nb;
 /// # struct Timer6;
 /// # impl hal::timer::CountDown for Timer6 {
 /// #     type Error = Infallible;
-/// #     type Time = Seconds;
-/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Seconds> { Ok(()) }
+/// #     type Time = Duration;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Duration> { Ok(()) }
 /// #     fn try_wait(&mut self) -> ::nb::Result<(), Infallible> { Ok(()) }
 /// # }
 /// ```
+#[cfg(feature = "nb")]
 pub trait CountDown {
     /// An enumeration of `CountDown` errors.
     ///
@@ -63,6 +65,10 @@ pub trait CountDown {
     type Error;
 
     /// The unit of time used by this timer
+    ///
+    /// Implementers are encouraged to use (or accept `Into`) the [`time::Duration`] type.
+    ///
+    /// [`time::Duration`]: ../time/struct.Duration.html
     type Time;
 
     /// Starts a new count down
@@ -75,16 +81,63 @@ pub trait CountDown {
     /// # Contract
     ///
     /// - If `Self: Periodic`, the timer will start a new count down right after the last one
-    /// finishes.
+    /// finishes, see the `Periodic` trait.
     /// - Otherwise the behavior of calling `try_wait` after the last call returned `Ok` is UNSPECIFIED.
     /// Implementers are suggested to panic on this scenario to signal a programmer error.
     fn try_wait(&mut self) -> nb::Result<(), Self::Error>;
 }
 
-/// Marker trait that indicates that a timer is periodic
-pub trait Periodic {}
+/// A periodic timer
+///
+/// # Contract
+///
+/// - Once started, the timer automatically starts a new count down of the current period right
+///   after the last one finishes, i.e. `try_wait` returns `Ok` once per period and calling it
+///   never stops the timer from auto-reloading.
+/// - `try_start(count)` sets the period to `count` and restarts the timer.
+///
+/// # Examples
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::timer::Periodic;
+///
+/// fn sample<T, F>(timer: &mut T, period: T::Time, mut sample: F) -> Result<(), T::Error>
+/// where
+///     T: Periodic,
+///     F: FnMut() -> bool,
+/// {
+///     timer.try_start(period)?;
+///
+///     loop {
+///         block!(timer.try_wait())?;
+///         if !sample() {
+///             return Ok(());
+///         }
+///     }
+/// }
+///
+/// # fn main() {}
+/// ```
+#[cfg(feature = "nb")]
+pub trait Periodic: CountDown {
+    /// Returns the current period
+    fn try_get_period(&self) -> Result<Self::Time, Self::Error>;
+
+    /// Sets a new period
+    ///
+    /// The new period takes effect after the current count down finishes; the running count down
+    /// is not restarted.
+    fn try_set_period<P>(&mut self, period: P) -> Result<(), Self::Error>
+    where
+        P: Into<Self::Time>;
+}
 
 /// Trait for cancelable countdowns.
+#[cfg(feature = "nb")]
 pub trait Cancel: CountDown {
     /// Tries to cancel this countdown.
     ///
@@ -94,3 +147,492 @@ pub trait Cancel: CountDown {
     /// An error is also returned if the countdown is not `Periodic` and has already expired.
     fn try_cancel(&mut self) -> Result<(), Self::Error>;
 }
+
+/// Trait for countdowns that can be paused and resumed
+///
+/// # Examples
+///
+/// Suspend a protocol timeout while servicing higher-priority work
+///
+/// ```
+/// use embedded_hal::timer::Pause;
+///
+/// fn service<T: Pause, F: FnOnce()>(timer: &mut T, work: F) -> Result<(), T::Error> {
+///     timer.try_pause()?;
+///     work();
+///     timer.try_resume()
+/// }
+/// ```
+#[cfg(feature = "nb")]
+pub trait Pause: CountDown {
+    /// Pauses the running countdown, keeping the remaining time
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the countdown is not running.
+    fn try_pause(&mut self) -> Result<(), Self::Error>;
+
+    /// Resumes a paused countdown from where it was paused
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the countdown is not paused.
+    fn try_resume(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Trait for countdowns that can report the time left before they finish
+#[cfg(feature = "nb")]
+pub trait Remaining: CountDown {
+    /// Returns the time left before the current countdown finishes
+    ///
+    /// Returns zero once the countdown has finished. The returned value is not updated while the
+    /// countdown is paused.
+    fn try_remaining(&self) -> Result<Self::Time, Self::Error>;
+}
+
+/// Trait for countdowns that can report the time elapsed since they were started
+///
+/// # Examples
+///
+/// Measure how long an operation took with the timer guarding its timeout
+///
+/// ```
+/// use embedded_hal::timer::Elapsed;
+///
+/// fn measure<T: Elapsed, F: FnOnce()>(
+///     timer: &mut T,
+///     timeout: T::Time,
+///     operation: F,
+/// ) -> Result<T::Time, T::Error> {
+///     timer.try_start(timeout)?;
+///     operation();
+///     timer.try_elapsed()
+/// }
+/// ```
+#[cfg(feature = "nb")]
+pub trait Elapsed: CountDown {
+    /// Returns the time elapsed since the current countdown was started
+    ///
+    /// For `Periodic` countdowns, the time is measured from the start of the current period. The
+    /// returned value is not updated while the countdown is paused.
+    fn try_elapsed(&self) -> Result<Self::Time, Self::Error>;
+}
+
+/// A monotonic clock
+///
+/// # Contract
+///
+/// - The instants returned by `try_now` MUST never decrease, i.e. `Instant` must be wide enough
+///   not to wrap around during the lifetime of the application.
+///
+/// # Examples
+///
+/// You can use this clock to implement timeouts
+///
+/// ```
+/// extern crate embedded_hal as hal;
+///
+/// use hal::timer::Clock;
+///
+/// fn wait_for<C, F>(clock: &C, timeout: C::Time, mut ready: F) -> Result<bool, C::Error>
+/// where
+///     C: Clock,
+///     F: FnMut() -> bool,
+/// {
+///     let deadline = clock.try_now()? + timeout;
+///
+///     while clock.try_now()? < deadline {
+///         if ready() {
+///             return Ok(true);
+///         }
+///     }
+///
+///     Ok(false)
+/// }
+///
+/// fn main() {
+///     let clock: SysTick = {
+///         // ..
+/// #       SysTick(core::cell::Cell::new(0))
+///     };
+///
+///     // times out after 1000 ticks
+///     assert!(!wait_for(&clock, 1_000, || false).unwrap());
+/// }
+///
+/// # use core::convert::Infallible;
+/// # struct SysTick(core::cell::Cell<u64>);
+/// # impl Clock for SysTick {
+/// #     type Error = Infallible;
+/// #     type Time = u64;
+/// #     type Instant = u64;
+/// #     fn try_now(&self) -> Result<u64, Infallible> {
+/// #         self.0.set(self.0.get() + 1);
+/// #         Ok(self.0.get())
+/// #     }
+/// # }
+/// ```
+pub trait Clock {
+    /// An enumeration of `Clock` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// The unit of time used to measure the distance between two instants
+    type Time;
+
+    /// A point in time
+    ///
+    /// Instants are ordered chronologically.
+    type Instant: Copy + Ord + Add<Self::Time, Output = Self::Instant> + Sub<Output = Self::Time>;
+
+    /// Returns the current instant
+    fn try_now(&self) -> Result<Self::Instant, Self::Error>;
+}
+
+/// An alarm firing at an absolute instant of a monotonic `Clock`
+///
+/// Unlike a `CountDown` started "now", an alarm armed at an absolute instant doesn't drift and
+/// can't race with the computation of the relative delay.
+///
+/// # Contract
+///
+/// - If the alarm is armed at an instant that has already passed, it MUST fire immediately.
+///
+/// # Examples
+///
+/// Transmit in a fixed slot of a TDMA frame
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::timer::{Alarm, Clock};
+///
+/// fn transmit_in_slot<A: Alarm, F: FnMut()>(
+///     alarm: &mut A,
+///     frame_start: A::Instant,
+///     slot_offset: A::Time,
+///     mut transmit: F,
+/// ) -> Result<(), A::Error> {
+///     alarm.try_set_alarm(frame_start + slot_offset)?;
+///     block!(alarm.try_wait_alarm())?;
+///     transmit();
+///     Ok(())
+/// }
+///
+/// # fn main() {}
+/// ```
+#[cfg(feature = "nb")]
+pub trait Alarm: Clock {
+    /// Arms the alarm to fire at `instant`
+    ///
+    /// Arming the alarm replaces any previously armed instant.
+    fn try_set_alarm(&mut self, instant: Self::Instant) -> Result<(), Self::Error>;
+
+    /// Non-blockingly "waits" until the alarm fires
+    ///
+    /// Returning `Ok` clears the alarm flag; the alarm must be armed again before the next wait.
+    fn try_wait_alarm(&mut self) -> nb::Result<(), Self::Error>;
+
+    /// Disarms the alarm and clears a pending alarm flag
+    fn try_clear_alarm(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A counter of external pulses
+///
+/// Typically implemented by a timer clocked from an external pin, counting the edges of the signal
+/// connected to that pin.
+///
+/// # Examples
+///
+/// Measure the speed of an anemometer
+///
+/// ```
+/// extern crate embedded_hal as hal;
+///
+/// use hal::prelude::*;
+/// use hal::preludes::timer::*;
+///
+/// fn main() {
+///     let mut counter: Tim2 = {
+///         // ..
+/// #       Tim2
+///     };
+///     let mut delay: Delay = {
+///         // ..
+/// #       Delay
+///     };
+///
+///     counter.try_reset().unwrap();
+///     delay.try_delay(1.secs()).unwrap();
+///     let pulses = counter.try_count().unwrap();
+///
+///     println!("Wind: {} pulses per second", pulses);
+/// }
+///
+/// # use core::convert::Infallible;
+/// # struct Tim2;
+/// # impl hal::timer::PulseCounter for Tim2 {
+/// #     type Error = Infallible;
+/// #     type Count = u16;
+/// #     fn try_count(&self) -> Result<u16, Self::Error> { Ok(0) }
+/// #     fn try_reset(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// # struct Delay;
+/// # impl hal::blocking::delay::DelayNs for Delay {
+/// #     type Error = Infallible;
+/// #     fn try_delay_ns(&mut self, _: u32) -> Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+pub trait PulseCounter {
+    /// An enumeration of `PulseCounter` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// The type of the value returned by `count`
+    type Count;
+
+    /// Returns the number of pulses counted since the last reset
+    ///
+    /// The count wraps around once it exceeds the range of `Count`.
+    fn try_count(&self) -> Result<Self::Count, Self::Error>;
+
+    /// Resets the count to zero
+    fn try_reset(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A free-running counter that wraps around to zero after reaching its maximum value
+///
+/// Typically implemented by a 16-bit or 32-bit hardware timer clocked at a constant rate. See
+/// [`Extended`] to extend such a counter to 64 bits.
+///
+/// [`Extended`]: struct.Extended.html
+pub trait FreeRunning {
+    /// An enumeration of `FreeRunning` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// The type of the value returned by `count`, e.g. `u16` for a 16-bit counter
+    type Count: Copy + Into<u64>;
+
+    /// The last value of the counter before it wraps around to zero
+    const MAX: Self::Count;
+
+    /// Returns the current value of the counter
+    fn try_count(&self) -> Result<Self::Count, Self::Error>;
+
+    /// Returns `true` if the counter wrapped around since the overflow flag was last cleared
+    fn try_has_wrapped(&self) -> Result<bool, Self::Error>;
+
+    /// Clears the overflow flag
+    fn try_clear_wrapped(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A 64-bit `Clock` built on a narrower `FreeRunning` counter
+///
+/// The wrapper keeps track of the number of times the counter wrapped around. `on_overflow` must
+/// be called at least once per wrap-around period of the counter, typically from the overflow
+/// interrupt handler of the timer. Overflows that are pending when `try_now` is called are
+/// accounted for, so the returned instants never decrease.
+///
+/// Instants and durations are expressed in ticks of the underlying counter.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::timer::{Clock, Extended};
+///
+/// let tim2: Tim2 = {
+///     // ..
+/// #   Tim2 { count: 0xFFFF, wrapped: false }
+/// };
+///
+/// let mut clock = Extended::new(tim2);
+///
+/// let before = clock.try_now().unwrap();
+///
+/// // in the overflow interrupt handler
+/// clock.on_overflow().unwrap();
+///
+/// let after = clock.try_now().unwrap();
+/// assert!(after >= before);
+///
+/// # use core::convert::Infallible;
+/// # struct Tim2 { count: u16, wrapped: bool }
+/// # impl embedded_hal::timer::FreeRunning for Tim2 {
+/// #     type Error = Infallible;
+/// #     type Count = u16;
+/// #     const MAX: u16 = 0xFFFF;
+/// #     fn try_count(&self) -> Result<u16, Infallible> { Ok(self.count) }
+/// #     fn try_has_wrapped(&self) -> Result<bool, Infallible> { Ok(self.wrapped) }
+/// #     fn try_clear_wrapped(&mut self) -> Result<(), Infallible> { self.wrapped = false; Ok(()) }
+/// # }
+/// ```
+pub struct Extended<T> {
+    counter: T,
+    overflows: u64,
+}
+
+impl<T> Extended<T>
+where
+    T: FreeRunning,
+{
+    /// Wraps a free-running `counter`
+    ///
+    /// The overflow flag of the counter is expected to be cleared.
+    pub fn new(counter: T) -> Self {
+        Extended {
+            counter,
+            overflows: 0,
+        }
+    }
+
+    /// Releases the counter
+    pub fn free(self) -> T {
+        self.counter
+    }
+
+    /// Accounts for a wrap-around of the counter
+    ///
+    /// Clears the overflow flag of the counter. Does nothing if the counter didn't wrap around.
+    pub fn on_overflow(&mut self) -> Result<(), T::Error> {
+        if self.counter.try_has_wrapped()? {
+            self.counter.try_clear_wrapped()?;
+            self.overflows += 1;
+        }
+
+        Ok(())
+    }
+
+    fn ticks(&self, overflows: u64, count: T::Count) -> u64 {
+        overflows * (T::MAX.into() + 1) + count.into()
+    }
+}
+
+impl<T> Clock for Extended<T>
+where
+    T: FreeRunning,
+{
+    type Error = T::Error;
+    type Time = u64;
+    type Instant = u64;
+
+    fn try_now(&self) -> Result<u64, T::Error> {
+        let before = self.counter.try_count()?;
+        let pending = self.counter.try_has_wrapped()?;
+        let count = self.counter.try_count()?;
+
+        // the counter wrapped around but `on_overflow` has not been called yet, either before
+        // the flag was read or between the two reads of the counter
+        let wrapped = pending || count.into() < before.into();
+        let overflows = if wrapped {
+            self.overflows + 1
+        } else {
+            self.overflows
+        };
+
+        Ok(self.ticks(overflows, count))
+    }
+}
+
+/// Clock source of a timer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClockSource<P, T> {
+    /// The internal (bus) clock of the timer
+    Internal,
+    /// Edges on the external input `P`
+    Pin(P),
+    /// The trigger output of timer `T`
+    Timer(T),
+}
+
+/// Trigger source of a timer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerSource<P, T> {
+    /// Edges on the external input `P`
+    Pin(P),
+    /// The trigger output of timer `T`
+    Timer(T),
+}
+
+/// Action taken by a timer when its trigger fires
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerMode {
+    /// The counter starts counting
+    Start,
+    /// The counter is reset and keeps counting
+    Reset,
+    /// The counter only counts while the trigger input is high
+    Gated,
+}
+
+/// Trait for timers whose clock source can be selected
+///
+/// # Examples
+///
+/// Chain two timers to build a prescaler
+///
+/// ```
+/// use embedded_hal::timer::{ClockSource, SetClockSource};
+///
+/// fn chain<T: SetClockSource>(slave: &mut T, master: T::Timer) -> Result<(), T::Error> {
+///     slave.try_set_clock_source(ClockSource::Timer(master))
+/// }
+/// ```
+pub trait SetClockSource {
+    /// An enumeration of `SetClockSource` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Identifies the external inputs that can clock the timer
+    type Pin;
+
+    /// Identifies the timers that can clock the timer
+    type Timer;
+
+    /// Selects the clock source of the timer
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `source` can't be connected to this timer.
+    fn try_set_clock_source(
+        &mut self,
+        source: ClockSource<Self::Pin, Self::Timer>,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Trait for timers that can be started, reset or gated by a trigger
+///
+/// This is what synchronizes timers with each other, or with external events, e.g. to start
+/// several PWM timers at once or to sample an ADC at a fixed point of a PWM period.
+pub trait SetTrigger {
+    /// An enumeration of `SetTrigger` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Identifies the external inputs that can trigger the timer
+    type Pin;
+
+    /// Identifies the timers that can trigger the timer
+    type Timer;
+
+    /// Makes the timer react to `source` as described by `mode`
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `source` can't be connected to this timer.
+    fn try_set_trigger(
+        &mut self,
+        source: TriggerSource<Self::Pin, Self::Timer>,
+        mode: TriggerMode,
+    ) -> Result<(), Self::Error>;
+
+    /// Disconnects the trigger, letting the timer run on its own
+    fn try_clear_trigger(&mut self) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,571 @@
+//! Bus transaction tracing
+//!
+//! [`Traced`] wraps an I2C, SPI or serial implementation and reports every transaction to a
+//! [`Sink`], optionally timestamped with a [`Clock`]. Drivers can then be debugged on hardware
+//! by inspecting or replaying the bus trace, without a logic analyzer.
+//!
+//! Any `FnMut(&Event<I>)` closure is a sink; [`Buffer`] records the trace into a byte buffer.
+//!
+//! Non-blocking operations, e.g. `spi::FullDuplex`, are traced one event per word.
+//!
+//! [`Traced`]: struct.Traced.html
+//! [`Sink`]: trait.Sink.html
+//! [`Clock`]: ../timer/trait.Clock.html
+//! [`Buffer`]: struct.Buffer.html
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! use embedded_hal::trace::{Buffer, Direction, Traced};
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], b: &mut [u8]) -> Result<(), Infallible> {
+//! #         b[0] = 0x33;
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! let mut storage = [0; 64];
+//! let mut i2c = Traced::new(I2c, Buffer::new(&mut storage));
+//!
+//! let mut id = [0];
+//! i2c.try_write_read(0x19, &[0x0F], &mut id).unwrap();
+//!
+//! let (_, trace) = i2c.free();
+//! let mut events = trace.events();
+//!
+//! let write = events.next().unwrap();
+//! assert_eq!(write.direction, Direction::Write);
+//! assert_eq!(write.address, Some(0x19));
+//! assert_eq!(write.data, &[0x0F]);
+//!
+//! let read = events.next().unwrap();
+//! assert_eq!(read.direction, Direction::Read);
+//! assert_eq!(read.data, &[0x33]);
+//!
+//! assert!(events.next().is_none());
+//! ```
+
+use core::convert::Infallible;
+
+use crate::blocking::{i2c, spi};
+use crate::serial;
+use crate::spi::FullDuplex;
+use crate::timer::Clock;
+
+/// Direction of a transfer, from the point of view of the controller
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// Data received
+    Read,
+    /// Data sent
+    Write,
+}
+
+/// A traced bus transaction
+#[derive(Debug)]
+pub struct Event<'a, I> {
+    /// Direction of the transfer
+    pub direction: Direction,
+    /// Address of the target, for I2C transactions
+    pub address: Option<u16>,
+    /// Data transferred
+    pub data: &'a [u8],
+    /// Instant at which the transaction was traced, if a clock is available
+    pub timestamp: Option<I>,
+}
+
+/// Receiver of traced transactions
+pub trait Sink<I> {
+    /// Records a transaction
+    fn record(&mut self, event: &Event<I>);
+}
+
+impl<F, I> Sink<I> for F
+where
+    F: FnMut(&Event<I>),
+{
+    fn record(&mut self, event: &Event<I>) {
+        self(event)
+    }
+}
+
+/// Sink recording the trace into a byte buffer
+///
+/// Each event is stored as a direction byte (`b'R'` or `b'W'`), the address as a big endian
+/// `u16` (`0xFFFF` if there's none), the length of the data as a big endian `u16` and the data.
+/// Timestamps are not recorded. Events that don't fit in the remaining space are dropped.
+pub struct Buffer<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+    dropped: u32,
+}
+
+const HEADER_LEN: usize = 5;
+const NO_ADDRESS: u16 = 0xFFFF;
+
+impl<'a> Buffer<'a> {
+    /// Creates a sink recording into `buffer`
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Buffer {
+            buffer,
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Returns the recorded trace
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// Returns the number of events dropped for lack of space
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    /// Iterates over the recorded events
+    pub fn events(&self) -> Events<'_> {
+        Events {
+            trace: self.as_slice(),
+        }
+    }
+}
+
+impl<'a, I> Sink<I> for Buffer<'a> {
+    fn record(&mut self, event: &Event<I>) {
+        let len = event.data.len();
+        if len > 0xFFFF || self.buffer.len() - self.len < HEADER_LEN + len {
+            self.dropped += 1;
+            return;
+        }
+
+        let address = event.address.unwrap_or(NO_ADDRESS);
+        let header = &mut self.buffer[self.len..self.len + HEADER_LEN];
+        header[0] = match event.direction {
+            Direction::Read => b'R',
+            Direction::Write => b'W',
+        };
+        header[1] = (address >> 8) as u8;
+        header[2] = address as u8;
+        header[3] = (len >> 8) as u8;
+        header[4] = len as u8;
+        self.len += HEADER_LEN;
+
+        self.buffer[self.len..self.len + len].copy_from_slice(event.data);
+        self.len += len;
+    }
+}
+
+/// Iterator over the events recorded in a [`Buffer`]
+///
+/// [`Buffer`]: struct.Buffer.html
+pub struct Events<'a> {
+    trace: &'a [u8],
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a, ()>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.trace.len() < HEADER_LEN {
+            return None;
+        }
+
+        let direction = if self.trace[0] == b'R' {
+            Direction::Read
+        } else {
+            Direction::Write
+        };
+        let address = u16::from(self.trace[1]) << 8 | u16::from(self.trace[2]);
+        let len = usize::from(self.trace[3]) << 8 | usize::from(self.trace[4]);
+        let data = &self.trace[HEADER_LEN..HEADER_LEN + len];
+        self.trace = &self.trace[HEADER_LEN + len..];
+
+        Some(Event {
+            direction,
+            address: if address == NO_ADDRESS {
+                None
+            } else {
+                Some(address)
+            },
+            data,
+            timestamp: None,
+        })
+    }
+}
+
+/// Placeholder clock of a [`Traced`] implementation without timestamps
+///
+/// [`Traced`]: struct.Traced.html
+pub struct NoClock;
+
+impl Clock for NoClock {
+    type Error = Infallible;
+    type Time = u64;
+    type Instant = u64;
+
+    fn try_now(&self) -> Result<u64, Infallible> {
+        Ok(0)
+    }
+}
+
+/// Implementation wrapper that traces every transaction
+pub struct Traced<T, S, C = NoClock> {
+    inner: T,
+    sink: S,
+    clock: Option<C>,
+}
+
+impl<T, S> Traced<T, S> {
+    /// Wraps `inner`, reporting transactions to `sink` without timestamps
+    pub fn new(inner: T, sink: S) -> Self {
+        Traced {
+            inner,
+            sink,
+            clock: None,
+        }
+    }
+}
+
+impl<T, S, C> Traced<T, S, C>
+where
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    /// Wraps `inner`, reporting transactions to `sink` timestamped with `clock`
+    ///
+    /// Transactions are reported without timestamp if reading the clock fails.
+    pub fn with_clock(inner: T, sink: S, clock: C) -> Self {
+        Traced {
+            inner,
+            sink,
+            clock: Some(clock),
+        }
+    }
+
+    /// Releases the wrapped implementation and the sink
+    pub fn free(self) -> (T, S) {
+        (self.inner, self.sink)
+    }
+
+    fn record(&mut self, direction: Direction, address: Option<u16>, data: &[u8]) {
+        record(&mut self.sink, &self.clock, direction, address, data)
+    }
+}
+
+fn record<S, C>(
+    sink: &mut S,
+    clock: &Option<C>,
+    direction: Direction,
+    address: Option<u16>,
+    data: &[u8],
+) where
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    sink.record(&Event {
+        direction,
+        address,
+        data,
+        timestamp: clock.as_ref().and_then(|clock| clock.try_now().ok()),
+    })
+}
+
+impl<T, S, C> FullDuplex<u8> for Traced<T, S, C>
+where
+    T: FullDuplex<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        let word = self.inner.try_read()?;
+        self.record(Direction::Read, None, &[word]);
+        Ok(word)
+    }
+
+    fn try_send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.inner.try_send(word)?;
+        self.record(Direction::Write, None, &[word]);
+        Ok(())
+    }
+}
+
+impl<T, S, C> spi::Transfer<u8> for Traced<T, S, C>
+where
+    T: spi::Transfer<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        self.record(Direction::Write, None, words);
+        let words = self.inner.try_transfer(words)?;
+        self.record(Direction::Read, None, words);
+        Ok(words)
+    }
+}
+
+impl<T, S, C> spi::Write<u8> for Traced<T, S, C>
+where
+    T: spi::Write<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.record(Direction::Write, None, words);
+        self.inner.try_write(words)
+    }
+}
+
+impl<T, S, C> spi::WriteIter<u8> for Traced<T, S, C>
+where
+    T: spi::WriteIter<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    /// Traces one event per word, as the words are consumed
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = u8>,
+    {
+        let (sink, clock) = (&mut self.sink, &self.clock);
+        let words = words
+            .into_iter()
+            .inspect(|&word| record(sink, clock, Direction::Write, None, &[word]));
+        self.inner.try_write_iter(words)
+    }
+}
+
+impl<T, S, C> spi::Transactional<u8> for Traced<T, S, C>
+where
+    T: spi::Transactional<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    /// Traces the words sent by every operation, then the words received by the transfers
+    fn try_exec<'a>(
+        &mut self,
+        operations: &mut [spi::Operation<'a, u8>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations.iter() {
+            match operation {
+                spi::Operation::Write(words) => self.record(Direction::Write, None, words),
+                spi::Operation::Transfer(words) => self.record(Direction::Write, None, words),
+            }
+        }
+
+        self.inner.try_exec(operations)?;
+
+        for operation in operations.iter() {
+            if let spi::Operation::Transfer(words) = operation {
+                self.record(Direction::Read, None, words);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, S, C, A> i2c::Read<A> for Traced<T, S, C>
+where
+    T: i2c::Read<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.inner.try_read(address, buffer)?;
+        self.record(Direction::Read, Some(address.into()), buffer);
+        Ok(())
+    }
+}
+
+impl<T, S, C, A> i2c::Write<A> for Traced<T, S, C>
+where
+    T: i2c::Write<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.record(Direction::Write, Some(address.into()), bytes);
+        self.inner.try_write(address, bytes)
+    }
+}
+
+impl<T, S, C, A> i2c::WriteIter<A> for Traced<T, S, C>
+where
+    T: i2c::WriteIter<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    /// Traces one event per byte, as the bytes are consumed
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        let (sink, clock) = (&mut self.sink, &self.clock);
+        let bytes = bytes
+            .into_iter()
+            .inspect(|&byte| record(sink, clock, Direction::Write, Some(address.into()), &[byte]));
+        self.inner.try_write_iter(address, bytes)
+    }
+}
+
+impl<T, S, C, A> i2c::WriteRead<A> for Traced<T, S, C>
+where
+    T: i2c::WriteRead<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.record(Direction::Write, Some(address.into()), bytes);
+        self.inner.try_write_read(address, bytes, buffer)?;
+        self.record(Direction::Read, Some(address.into()), buffer);
+        Ok(())
+    }
+}
+
+impl<T, S, C, A> i2c::Transactional<A> for Traced<T, S, C>
+where
+    T: i2c::Transactional<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    /// Traces the bytes written by every operation, then the bytes read
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations.iter() {
+            if let i2c::Operation::Write(bytes) = operation {
+                self.record(Direction::Write, Some(address.into()), bytes);
+            }
+        }
+
+        self.inner.try_exec(address, operations)?;
+
+        for operation in operations.iter() {
+            if let i2c::Operation::Read(buffer) = operation {
+                self.record(Direction::Read, Some(address.into()), buffer);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, S, C, A> i2c::WriteIterRead<A> for Traced<T, S, C>
+where
+    T: i2c::WriteIterRead<A>,
+    C: Clock,
+    S: Sink<C::Instant>,
+    A: i2c::AddressMode + Copy + Into<u16>,
+{
+    type Error = T::Error;
+
+    /// Traces one event per byte written, as the bytes are consumed
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        {
+            let (sink, clock) = (&mut self.sink, &self.clock);
+            let bytes = bytes.into_iter().inspect(|&byte| {
+                record(sink, clock, Direction::Write, Some(address.into()), &[byte])
+            });
+            self.inner.try_write_iter_read(address, bytes, buffer)?;
+        }
+        self.record(Direction::Read, Some(address.into()), buffer);
+        Ok(())
+    }
+}
+
+impl<T, S, C> serial::Read<u8> for Traced<T, S, C>
+where
+    T: serial::Read<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        let word = self.inner.try_read()?;
+        self.record(Direction::Read, None, &[word]);
+        Ok(word)
+    }
+}
+
+impl<T, S, C> serial::Write<u8> for Traced<T, S, C>
+where
+    T: serial::Write<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.inner.try_write(word)?;
+        self.record(Direction::Write, None, &[word]);
+        Ok(())
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.inner.try_flush()
+    }
+}
+
+impl<T, S, C> crate::blocking::serial::Write<u8> for Traced<T, S, C>
+where
+    T: crate::blocking::serial::Write<u8>,
+    C: Clock,
+    S: Sink<C::Instant>,
+{
+    type Error = T::Error;
+
+    fn try_bwrite_all(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.record(Direction::Write, None, buffer);
+        self.inner.try_bwrite_all(buffer)
+    }
+
+    fn try_bflush(&mut self) -> Result<(), Self::Error> {
+        self.inner.try_bflush()
+    }
+}
@@ -1,5 +1,7 @@
 //! Traits for interactions with a processors watchdog timer.
 
+use crate::time::Duration;
+
 /// Feeds an existing watchdog to ensure the processor isn't reset. Sometimes
 /// commonly referred to as "kicking" or "refreshing".
 pub trait Watchdog {
@@ -15,20 +17,49 @@ pub trait Watchdog {
 
 /// Enables A watchdog timer to reset the processor if software is frozen or
 /// stalled.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::time::U32Ext;
+/// use embedded_hal::watchdog::{Watchdog, WatchdogEnable};
+///
+/// fn supervise<W: Watchdog + WatchdogEnable>(watchdog: &mut W) {
+///     watchdog.try_start(2.secs()).ok();
+///
+///     loop {
+///         // .. do work for less than 2 seconds
+///         watchdog.try_feed().ok();
+/// #       break;
+///     }
+/// }
+/// ```
 pub trait WatchdogEnable {
     /// An enumeration of `WatchdogEnable` errors.
     ///
     /// For infallible implementations, will be `Infallible`
     type Error;
 
-    /// Unit of time used by the watchdog
-    type Time;
-
     /// Starts the watchdog with a given period, typically once this is done
     /// the watchdog needs to be kicked periodically or the processor is reset.
+    ///
+    /// Implementations use the longest timeout supported by the hardware that is not longer than
+    /// `period`.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `period` is shorter than the shortest supported timeout.
     fn try_start<T>(&mut self, period: T) -> Result<(), Self::Error>
     where
-        T: Into<Self::Time>;
+        T: Into<Duration>;
+}
+
+/// Reports the time left before a running watchdog resets the processor.
+///
+/// Supervision tasks can use it to schedule feeds adaptively, or to log near misses.
+pub trait WatchdogRemaining: WatchdogEnable {
+    /// Returns the time left before the watchdog resets the processor, if it's not fed
+    fn try_remaining(&self) -> Result<Duration, Self::Error>;
 }
 
 /// Disables a running watchdog timer so the processor won't be reset.
@@ -41,3 +72,63 @@ pub trait WatchdogDisable {
     /// Disables the watchdog
     fn try_disable(&mut self) -> Result<(), Self::Error>;
 }
+
+/// Windowed watchdog timer: feeding it too late *or too early* resets the processor.
+///
+/// After each feed, the watchdog must be fed again once `window_start` has elapsed but before
+/// `timeout` has elapsed. Catching early feeds detects software stuck in a tight loop that keeps
+/// feeding the watchdog, which safety standards often require.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::time::U32Ext;
+/// use embedded_hal::watchdog::WindowWatchdog;
+///
+/// fn supervise<W: WindowWatchdog>(watchdog: &mut W) {
+///     watchdog.try_start_window(50.millis(), 1.secs()).ok();
+///
+///     loop {
+///         // .. do work for more than 50 ms and less than 1 second
+///         watchdog.try_feed().ok();
+/// #       break;
+///     }
+/// }
+/// ```
+pub trait WindowWatchdog: Watchdog {
+    /// Starts the watchdog with the given window, typically once this is done
+    /// the watchdog needs to be fed within the window or the processor is reset.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if `window_start` is not shorter than `timeout`.
+    fn try_start_window<S, T>(&mut self, window_start: S, timeout: T) -> Result<(), Self::Error>
+    where
+        S: Into<Duration>,
+        T: Into<Duration>;
+}
+
+/// Configures whether the watchdog keeps counting while the processor is asleep or halted by a
+/// debugger.
+pub trait WatchdogFreeze {
+    /// An enumeration of `WatchdogFreeze` errors.
+    ///
+    /// For infallible implementations, will be `Infallible`
+    type Error;
+
+    /// Freezes (`true`) or keeps running (`false`) the watchdog in low-power modes
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the hardware doesn't support this configuration, or if it can
+    /// only be changed through non-volatile settings (e.g. option bytes).
+    fn try_freeze_in_sleep(&mut self, freeze: bool) -> Result<(), Self::Error>;
+
+    /// Freezes (`true`) or keeps running (`false`) the watchdog while the processor is halted by
+    /// a debugger
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the hardware doesn't support this configuration.
+    fn try_freeze_in_debug(&mut self, freeze: bool) -> Result<(), Self::Error>;
+}